@@ -0,0 +1,89 @@
+//! Measures the cost of rendering calendar views with and without the shared `Intl` formatters.
+//!
+//! Runs in a browser with the wasm-bindgen test runner, as `Intl` isn't available natively:
+//!
+//! ```text
+//! wasm-pack test --headless --chrome --release -- --features bench --bench formatters
+//! ```
+//!
+//! or `cargo make bench`.
+//!
+//! The timings are logged to the console, once with the formatter caches and once bypassing them,
+//! so that every value is formatted with a newly constructed formatter.
+
+#![cfg(target_arch = "wasm32")]
+
+use js_sys::*;
+use seed_calendar::calendar::Calendar;
+use seed_calendar::set_formatter_caching;
+use seed_calendar::view::{MonthView, WeeksView};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const ITERATIONS: u32 = 50;
+
+#[wasm_bindgen_test]
+fn render_month_view() {
+    compare("month view", || {
+        MonthView::<()>::new(2021, 4)
+            .with_locale("de-DE")
+            .show_weekdays()
+            .show_week_numbers()
+            .show_header()
+            .into_node();
+    });
+}
+
+#[wasm_bindgen_test]
+fn render_month_view_with_secondary_calendar() {
+    compare("month view with secondary calendar", || {
+        MonthView::<()>::new(2021, 4)
+            .with_locale("de-DE")
+            .with_calendar(Calendar::Gregorian)
+            .with_secondary_calendar("islamic-umalqura")
+            .show_weekdays()
+            .show_header()
+            .into_node();
+    });
+}
+
+#[wasm_bindgen_test]
+fn render_weeks_view() {
+    compare("weeks view", || {
+        WeeksView::<()>::new(2021, 4)
+            .with_locale("de-DE")
+            .show_weekdays()
+            .into_node();
+    });
+}
+
+// Helpers
+
+fn compare(name: &str, mut render: impl FnMut()) {
+    set_formatter_caching(true);
+    let cached = measure(&mut render);
+
+    set_formatter_caching(false);
+    let uncached = measure(&mut render);
+    set_formatter_caching(true);
+
+    console_log!(
+        "render {}: {:.3} ms cached, {:.3} ms uncached",
+        name,
+        cached,
+        uncached
+    );
+}
+
+// The average time of an iteration in milliseconds, after a warm-up iteration
+fn measure(mut f: impl FnMut()) -> f64 {
+    f();
+
+    let start = Date::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+
+    (Date::now() - start) / f64::from(ITERATIONS)
+}
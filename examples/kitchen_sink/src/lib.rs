@@ -1,5 +1,8 @@
-use chrono::{IsoWeek, NaiveDate};
+use chrono::{Datelike, NaiveDate};
 use seed::{prelude::*, *};
+use seed_calendar::calendar::Calendar;
+use seed_calendar::component::date_picker;
+use seed_calendar::core::Week;
 
 fn init(_: Url, _: &mut impl Orders<Msg>) -> Model {
     Model {
@@ -9,6 +12,11 @@ fn init(_: Url, _: &mut impl Orders<Msg>) -> Model {
         end: None,
         start_week: None,
         end_week: None,
+        date_picker: date_picker::Model::new(2021, 4)
+            .with_mode(date_picker::SelectionMode::Range)
+            .with_today(seed_calendar::today())
+            .with_disabled(|date| date.weekday().number_from_monday() > 5),
+        committed: None,
     }
 }
 // MODEL
@@ -18,22 +26,30 @@ struct Model {
     month: u32,
     start: Option<NaiveDate>,
     end: Option<NaiveDate>,
-    start_week: Option<IsoWeek>,
-    end_week: Option<IsoWeek>,
+    start_week: Option<Week>,
+    end_week: Option<Week>,
+    date_picker: date_picker::Model,
+    committed: Option<(NaiveDate, NaiveDate)>,
 }
 
 // UPDATE
 
 enum Msg {
+    Navigate(i32, u32),
     SelectYear(i32),
     SelectMonth(u32),
     SelectDate(NaiveDate),
-    SelectWeek(IsoWeek),
+    SelectWeek(Week),
+    DatePicker(date_picker::Msg),
 }
 
 #[allow(clippy::needless_pass_by_value)]
-fn update(msg: Msg, model: &mut Model, _orders: &mut impl Orders<Msg>) {
+fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
     match msg {
+        Msg::Navigate(year, month) => {
+            model.year = year;
+            model.month = month;
+        }
         Msg::SelectYear(year) => model.year = year,
         Msg::SelectMonth(month) => model.month = month,
         Msg::SelectDate(date) => match (model.start, model.end) {
@@ -54,32 +70,117 @@ fn update(msg: Msg, model: &mut Model, _orders: &mut impl Orders<Msg>) {
                 model.end_week = None
             }
         },
+        Msg::DatePicker(msg) => match date_picker::update(msg, &mut model.date_picker, orders) {
+            Some(date_picker::Output::SelectionCommitted(start, end)) => {
+                model.committed = Some((start, end))
+            }
+            Some(date_picker::Output::SelectionCleared) => model.committed = None,
+            Some(date_picker::Output::SelectionStarted(_))
+            | Some(date_picker::Output::SelectionRejected(..))
+            | Some(date_picker::Output::DateSelected(_))
+            | Some(date_picker::Output::DateDeselected(_))
+            | None => (),
+        },
     }
 }
 
 // VIEW
 
 fn view(model: &Model) -> impl IntoNodes<Msg> {
+    let today = seed_calendar::today();
+    let hebrew = Calendar::Hebrew.from_date(NaiveDate::from_ymd(model.year, model.month, 1));
+
     div![
         h4!["Month"],
         seed_calendar::view::MonthView::new(model.year, model.month)
             .maybe_with_selection(model.start, model.end)
             .show_week_numbers()
             .show_weekdays()
+            .show_header()
+            .with_today(today)
+            .on_click(Msg::SelectDate)
+            .on_navigate(Msg::Navigate),
+        h4!["Month (Thai digits)"],
+        seed_calendar::view::MonthView::new(model.year, model.month)
+            .with_locale("th-TH-u-nu-thai")
+            .show_week_numbers()
+            .show_weekdays()
+            .show_header(),
+        h4!["Month (Arabic, right-to-left)"],
+        seed_calendar::view::MonthView::new(model.year, model.month)
+            .maybe_with_selection(model.start, model.end)
+            .with_locale("ar-EG")
+            .show_weekdays()
+            .show_header()
+            .on_click(Msg::SelectDate)
+            .on_navigate(Msg::Navigate),
+        h4!["Month (Hebrew calendar)"],
+        seed_calendar::view::MonthView::new(hebrew.year, hebrew.month)
+            .maybe_with_selection(model.start, model.end)
+            .with_calendar(Calendar::Hebrew)
+            .with_today(today)
+            .show_weekdays()
+            .show_header()
             .on_click(Msg::SelectDate),
+        h4!["Month (with Hijri dates)"],
+        seed_calendar::view::MonthView::new(model.year, model.month)
+            .maybe_with_selection(model.start, model.end)
+            .with_secondary_calendar(Calendar::IslamicUmalqura.identifier())
+            .show_weekdays()
+            .show_header()
+            .on_click(Msg::SelectDate)
+            .on_navigate(Msg::Navigate),
+        h4!["Month (Japanese calendar)"],
+        seed_calendar::view::MonthView::new(model.year, model.month)
+            .with_locale("ja-JP")
+            .with_calendar(Calendar::Japanese)
+            .show_weekdays()
+            .show_header(),
+        h4!["Custom day cells"],
+        seed_calendar::view::MonthView::new(model.year, model.month)
+            .show_weekdays()
+            .with_day_renderer(|date, state| {
+                div![
+                    date.day(),
+                    IF!(state.in_month && !state.weekend => small![C!["price"], "$99"]),
+                ]
+            }),
         h4!["Weeks"],
         seed_calendar::view::WeeksView::new(model.year, model.month)
             .maybe_with_selection(model.start_week, model.end_week)
             .show_weekdays()
+            .with_today(today)
             .on_click(Msg::SelectWeek),
         h4!["Year"],
         seed_calendar::view::YearsView::decade_from(2010)
             .with_selected(model.year)
+            .with_today(today)
             .on_click(Msg::SelectYear),
         h4!["Months"],
         seed_calendar::view::MonthsView::new()
+            .with_year(model.year)
             .with_selected(model.month)
+            .with_today(today)
             .on_click(Msg::SelectMonth),
+        h4!["Months (Russian, long, 4x3)"],
+        seed_calendar::view::MonthsView::new()
+            .with_locale("ru")
+            .with_style(seed_calendar::view::MonthStyle::Long)
+            .with_layout(seed_calendar::view::MonthsLayout::FourByThree)
+            .with_selected(model.month)
+            .on_click(Msg::SelectMonth),
+        h4!["Months (Persian calendar)"],
+        seed_calendar::view::MonthsView::new()
+            .with_locale("fa-IR")
+            .with_calendar(Calendar::Persian)
+            .with_style(seed_calendar::view::MonthStyle::Long)
+            .with_today(today),
+        h4!["Date picker"],
+        date_picker::view(&model.date_picker).map_msg(Msg::DatePicker),
+        p![match model.committed {
+            Some((start, end)) => format!("Committed: {} - {}", start, end),
+            None => String::from("Nothing committed"),
+        }],
     ]
 }
 
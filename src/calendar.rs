@@ -0,0 +1,284 @@
+//! Calendar systems other than the Gregorian, used to lay out months and years.
+//!
+//! Dates are always passed in and out of the views as Gregorian `NaiveDate`s. A `Calendar` only
+//! determines how those dates are grouped into months and years, and how they're labelled.
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::util::locale;
+
+mod hebrew;
+mod islamic;
+mod persian;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Calendar {
+    Gregorian,
+    /// The Thai solar calendar. Months are Gregorian, years are counted from 543 BCE.
+    Buddhist,
+    /// Months and years are Gregorian. Years are labelled by era, e.g. Reiwa 3, but are still
+    /// numbered as Gregorian years when passed to and from the views.
+    Japanese,
+    /// The Solar Hijri calendar.
+    Persian,
+    /// Years are numbered from the creation epoch and start with Tishri. Leap years insert
+    /// Adar I as the sixth month, making Adar II the seventh.
+    Hebrew,
+    /// The tabular Islamic calendar, with the civil (Friday) epoch.
+    IslamicCivil,
+    /// The observational Islamic calendar, as reported by `Intl`. Falls back to the tabular
+    /// calendar where it isn't supported.
+    Islamic,
+    /// The Umm al-Qura calendar of Saudi Arabia, as reported by `Intl`. Falls back to the tabular
+    /// calendar where it isn't supported.
+    IslamicUmalqura,
+}
+
+/// A date in some calendar. Months are numbered from 1, in the order they occur within the year.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Calendar {
+    /// The BCP 47 calendar identifier, as used by the `-u-ca-` locale extension.
+    pub fn identifier(self) -> &'static str {
+        match self {
+            Calendar::Gregorian => "gregory",
+            Calendar::Buddhist => "buddhist",
+            Calendar::Japanese => "japanese",
+            Calendar::Persian => "persian",
+            Calendar::Hebrew => "hebrew",
+            Calendar::IslamicCivil => "islamic-civil",
+            Calendar::Islamic => "islamic",
+            Calendar::IslamicUmalqura => "islamic-umalqura",
+        }
+    }
+
+    pub fn from_identifier(identifier: &str) -> Option<Calendar> {
+        Some(match identifier {
+            "gregory" => Calendar::Gregorian,
+            "buddhist" => Calendar::Buddhist,
+            "japanese" => Calendar::Japanese,
+            "persian" => Calendar::Persian,
+            "hebrew" => Calendar::Hebrew,
+            "islamic-civil" => Calendar::IslamicCivil,
+            "islamic" => Calendar::Islamic,
+            "islamic-umalqura" => Calendar::IslamicUmalqura,
+            _ => return None,
+        })
+    }
+
+    pub fn from_date(self, date: NaiveDate) -> CalendarDate {
+        let fixed = date.num_days_from_ce() as i64;
+
+        let (year, month, day) = match self {
+            Calendar::Gregorian | Calendar::Japanese => (date.year(), date.month(), date.day()),
+            Calendar::Buddhist => (date.year() + BUDDHIST_YEAR_OFFSET, date.month(), date.day()),
+            Calendar::Persian => persian::from_fixed(fixed),
+            Calendar::Hebrew => hebrew::from_fixed(fixed),
+            Calendar::IslamicCivil => islamic::from_fixed(fixed),
+            Calendar::Islamic | Calendar::IslamicUmalqura => {
+                helpers::from_intl(self, date).unwrap_or_else(|| islamic::from_fixed(fixed))
+            }
+        };
+
+        CalendarDate { year, month, day }
+    }
+
+    /// The Gregorian date of the given day, or `None` if there's no such day in this calendar.
+    pub fn to_date(self, year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+        if month < 1 || month > self.months_in_year(year) || day < 1 {
+            return None;
+        }
+
+        match self {
+            Calendar::Gregorian | Calendar::Japanese => NaiveDate::from_ymd_opt(year, month, day),
+            Calendar::Buddhist => NaiveDate::from_ymd_opt(year - BUDDHIST_YEAR_OFFSET, month, day),
+            Calendar::Persian if day <= persian::days_in_month(year, month) => {
+                helpers::from_fixed(persian::to_fixed(year, month, day))
+            }
+            Calendar::Hebrew if day <= hebrew::days_in_month(year, month) => {
+                helpers::from_fixed(hebrew::to_fixed(year, month, day))
+            }
+            Calendar::IslamicCivil if day <= islamic::days_in_month(year, month) => {
+                helpers::from_fixed(islamic::to_fixed(year, month, day))
+            }
+            Calendar::Islamic | Calendar::IslamicUmalqura => {
+                helpers::search(self, year, month, day)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn months_in_year(self, year: i32) -> u32 {
+        match self {
+            Calendar::Hebrew => hebrew::months_in_year(year),
+            _ => 12,
+        }
+    }
+
+    pub fn days_in_month(self, year: i32, month: u32) -> u32 {
+        let (next_year, next_month) = self.next_month(year, month);
+
+        match (
+            self.to_date(year, month, 1),
+            self.to_date(next_year, next_month, 1),
+        ) {
+            (Some(first), Some(next)) => (next - first).num_days() as u32,
+            _ => 0,
+        }
+    }
+
+    pub fn first_of_month(self, year: i32, month: u32) -> Option<NaiveDate> {
+        self.to_date(year, month, 1)
+    }
+
+    pub fn previous_month(self, year: i32, month: u32) -> (i32, u32) {
+        if month <= 1 {
+            (year - 1, self.months_in_year(year - 1))
+        } else {
+            (year, month - 1)
+        }
+    }
+
+    pub fn next_month(self, year: i32, month: u32) -> (i32, u32) {
+        if month >= self.months_in_year(year) {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        }
+    }
+
+    /// Moves the date by a number of months in this calendar, keeping the day of the month where
+    /// possible, and otherwise moving to the last day of the target month.
+    pub fn add_months(self, date: NaiveDate, months: i32) -> NaiveDate {
+        let CalendarDate { year, month, day } = self.from_date(date);
+
+        let (year, month) = (0..months.abs()).fold((year, month), |(year, month), _| {
+            if months < 0 {
+                self.previous_month(year, month)
+            } else {
+                self.next_month(year, month)
+            }
+        });
+
+        (1..=day)
+            .rev()
+            .find_map(|day| self.to_date(year, month, day))
+            .unwrap_or(date)
+    }
+
+    /// Adds the `-u-ca-` extension for this calendar to a locale, replacing any calendar already
+    /// set, so that `Intl` formats dates in this calendar.
+    pub fn apply_to_locale(self, locale: &str) -> String {
+        locale::with_unicode_keyword(locale, "ca", self.identifier())
+    }
+}
+
+const BUDDHIST_YEAR_OFFSET: i32 = 543;
+
+impl Default for Calendar {
+    fn default() -> Self {
+        Calendar::Gregorian
+    }
+}
+
+// Helpers
+
+mod helpers {
+    use super::{islamic, Calendar};
+    use chrono::NaiveDate;
+
+    // How far off the tabular Islamic calendar can be from the observational ones
+    const MAX_ISLAMIC_DEVIATION: usize = 4;
+
+    pub fn from_fixed(date: i64) -> Option<NaiveDate> {
+        NaiveDate::from_num_days_from_ce_opt(date as i32)
+    }
+
+    // Starts from the tabular calendar, and corrects by the difference reported by `Intl`
+    pub fn search(calendar: Calendar, year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+        let target = islamic::to_fixed(year, month, day);
+        let mut date = target;
+
+        for _ in 0..MAX_ISLAMIC_DEVIATION {
+            let found = calendar.from_date(from_fixed(date)?);
+            if (found.year, found.month, found.day) == (year, month, day) {
+                return from_fixed(date);
+            }
+
+            let offset = target - islamic::to_fixed(found.year, found.month, found.day);
+            if offset == 0 {
+                return None;
+            }
+            date += offset;
+        }
+
+        None
+    }
+
+    pub fn from_intl(calendar: Calendar, date: NaiveDate) -> Option<(i32, u32, u32)> {
+        use crate::util::{formatters, intl};
+        use js_sys::*;
+        use wasm_bindgen::prelude::*;
+
+        if !intl::is_available() {
+            return None;
+        }
+
+        let locale = format!("en-u-ca-{}-nu-latn", calendar.identifier());
+        let formatter = formatters::date_time_format(
+            &locale,
+            &[
+                ("year", "numeric"),
+                ("month", "numeric"),
+                ("day", "numeric"),
+            ],
+        )
+        .ok()?;
+
+        // Unsupported calendars silently resolve to the Gregorian
+        let resolved = Reflect::get(&formatter.resolved_options(), &JsValue::from("calendar"))
+            .ok()?
+            .as_string()?;
+        if resolved != calendar.identifier() {
+            return None;
+        }
+
+        // The formatter formats in UTC, so the day doesn't depend on the time zone
+        let datetime = date.and_hms(12, 0, 0);
+        let js_date = Date::new(&JsValue::from(datetime.timestamp_millis() as f64));
+
+        let parts = formatter.format_to_parts(&js_date);
+        let part = |name: &str| {
+            parts
+                .iter()
+                .find(|part| {
+                    Reflect::get(part, &JsValue::from("type"))
+                        .ok()
+                        .and_then(|kind| kind.as_string())
+                        .as_deref()
+                        == Some(name)
+                })
+                .and_then(|part| Reflect::get(&part, &JsValue::from("value")).ok())
+                .and_then(|value| value.as_string())
+                .and_then(|value| {
+                    value
+                        .chars()
+                        .filter(char::is_ascii_digit)
+                        .collect::<String>()
+                        .parse::<i64>()
+                        .ok()
+                })
+        };
+
+        Some((
+            part("year")? as i32,
+            part("month")? as u32,
+            part("day")? as u32,
+        ))
+    }
+}
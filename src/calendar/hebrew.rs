@@ -0,0 +1,123 @@
+// The arithmetic Hebrew calendar, as described in "Calendrical Calculations" by Dershowitz and
+// Reingold. Dates are fixed day numbers, counting January 1, 1 CE as day 1.
+//
+// Months are numbered in the order they occur within a year, starting from Tishri. In leap
+// years Adar I is inserted as month 6, and Adar II follows as month 7.
+
+const EPOCH: i64 = -1373427;
+
+// Biblical month numbers, counting from Nisan, as used by the calculations below
+const NISAN: i64 = 1;
+const ADAR: i64 = 12;
+const ADAR_II: i64 = 13;
+const TISHRI: i64 = 7;
+
+pub fn is_leap_year(year: i32) -> bool {
+    (7 * year as i64 + 1).rem_euclid(19) < 7
+}
+
+pub fn months_in_year(year: i32) -> u32 {
+    if is_leap_year(year) {
+        13
+    } else {
+        12
+    }
+}
+
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    biblical_days_in_month(year, biblical_month(year, month)) as u32
+}
+
+pub fn to_fixed(year: i32, month: u32, day: u32) -> i64 {
+    let month = biblical_month(year, month);
+    let last_month = if is_leap_year(year) { ADAR_II } else { ADAR };
+
+    let days_before_month: i64 = if month < TISHRI {
+        (TISHRI..=last_month)
+            .chain(NISAN..month)
+            .map(|month| biblical_days_in_month(year, month))
+            .sum()
+    } else {
+        (TISHRI..month)
+            .map(|month| biblical_days_in_month(year, month))
+            .sum()
+    };
+
+    new_year(year) + days_before_month + day as i64 - 1
+}
+
+pub fn from_fixed(date: i64) -> (i32, u32, u32) {
+    let approx = ((98496 * (date - EPOCH)).div_euclid(35975351) + 1) as i32;
+    let year = (approx - 1..)
+        .take_while(|&year| new_year(year) <= date)
+        .last()
+        .unwrap();
+
+    let month = (1..=months_in_year(year))
+        .find(|&month| date < to_fixed(year, month, 1) + days_in_month(year, month) as i64)
+        .unwrap();
+    let day = (date - to_fixed(year, month, 1) + 1) as u32;
+
+    (year, month, day)
+}
+
+fn biblical_month(year: i32, month: u32) -> i64 {
+    let month = month as i64;
+
+    match (is_leap_year(year), month) {
+        (_, 1..=5) => month + 6,
+        (false, 6) => ADAR,
+        (false, _) => month - 6,
+        (true, 6) => ADAR,
+        (true, 7) => ADAR_II,
+        (true, _) => month - 7,
+    }
+}
+
+fn biblical_days_in_month(year: i32, month: i64) -> i64 {
+    let days_in_year = new_year(year + 1) - new_year(year);
+    let long_marheshvan = days_in_year % 10 == 5;
+    let short_kislev = days_in_year % 10 == 3;
+
+    if matches!(month, 2 | 4 | 6 | 10 | ADAR_II)
+        || (month == ADAR && !is_leap_year(year))
+        || (month == 8 && !long_marheshvan)
+        || (month == 9 && short_kislev)
+    {
+        29
+    } else {
+        30
+    }
+}
+
+fn elapsed_days(year: i32) -> i64 {
+    let months_elapsed = (235 * year as i64 - 234).div_euclid(19);
+    let parts_elapsed = 12084 + 13753 * months_elapsed;
+    let days = 29 * months_elapsed + parts_elapsed.div_euclid(25920);
+
+    if (3 * (days + 1)).rem_euclid(7) < 3 {
+        days + 1
+    } else {
+        days
+    }
+}
+
+fn year_length_correction(year: i32) -> i64 {
+    let (ny0, ny1, ny2) = (
+        elapsed_days(year - 1),
+        elapsed_days(year),
+        elapsed_days(year + 1),
+    );
+
+    if ny2 - ny1 == 356 {
+        2
+    } else if ny1 - ny0 == 382 {
+        1
+    } else {
+        0
+    }
+}
+
+fn new_year(year: i32) -> i64 {
+    EPOCH + elapsed_days(year) + year_length_correction(year)
+}
@@ -0,0 +1,37 @@
+// The tabular Islamic calendar, with the civil (Friday) epoch, as described in
+// "Calendrical Calculations" by Dershowitz and Reingold. Dates are fixed day numbers, counting
+// January 1, 1 CE as day 1.
+
+const EPOCH: i64 = 227015;
+
+pub fn is_leap_year(year: i32) -> bool {
+    (14 + 11 * year as i64).rem_euclid(30) < 11
+}
+
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    if month % 2 == 1 || (month == 12 && is_leap_year(year)) {
+        30
+    } else {
+        29
+    }
+}
+
+pub fn to_fixed(year: i32, month: u32, day: u32) -> i64 {
+    let (year, month, day) = (year as i64, month as i64, day as i64);
+
+    day + 29 * (month - 1)
+        + (6 * month - 1).div_euclid(11)
+        + (year - 1) * 354
+        + (3 + 11 * year).div_euclid(30)
+        + EPOCH
+        - 1
+}
+
+pub fn from_fixed(date: i64) -> (i32, u32, u32) {
+    let year = (30 * (date - EPOCH) + 10646).div_euclid(10631) as i32;
+    let prior_days = date - to_fixed(year, 1, 1);
+    let month = ((11 * prior_days + 330).div_euclid(325)) as u32;
+    let day = (date - to_fixed(year, month, 1) + 1) as u32;
+
+    (year, month, day)
+}
@@ -0,0 +1,51 @@
+// The arithmetic Persian (Solar Hijri) calendar, using the 33-year leap cycle also used by ICU.
+// Dates are fixed day numbers, counting January 1, 1 CE as day 1.
+
+// 1 Farvardin 1 AP
+const EPOCH: i64 = 226895;
+
+pub fn is_leap_year(year: i32) -> bool {
+    (25 * year as i64 + 11).rem_euclid(33) < 8
+}
+
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1..=6 => 31,
+        7..=11 => 30,
+        _ if is_leap_year(year) => 30,
+        _ => 29,
+    }
+}
+
+fn days_before_month(month: u32) -> i64 {
+    let month = month as i64 - 1;
+
+    if month < 6 {
+        31 * month
+    } else {
+        30 * month + 6
+    }
+}
+
+fn new_year(year: i32) -> i64 {
+    let year = year as i64;
+
+    EPOCH + 365 * (year - 1) + (8 * year + 21).div_euclid(33)
+}
+
+pub fn to_fixed(year: i32, month: u32, day: u32) -> i64 {
+    new_year(year) + days_before_month(month) + day as i64 - 1
+}
+
+pub fn from_fixed(date: i64) -> (i32, u32, u32) {
+    let year = (1 + (33 * (date - EPOCH) + 3).div_euclid(12053)) as i32;
+    let day_of_year = date - new_year(year);
+    let month = if day_of_year < 216 {
+        day_of_year / 31 + 1
+    } else {
+        (day_of_year - 6) / 30 + 1
+    } as u32;
+    let day = (day_of_year - days_before_month(month) + 1) as u32;
+
+    (year, month, day)
+}
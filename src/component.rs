@@ -0,0 +1 @@
+pub mod date_picker;
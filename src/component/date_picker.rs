@@ -0,0 +1,572 @@
+use chrono::{NaiveDate, Weekday};
+use seed::{prelude::*, *};
+use std::collections::{BTreeSet, HashMap};
+use std::iter;
+use std::rc::Rc;
+
+use crate::calendar::Calendar;
+use crate::core::{MonthGrid, Selection};
+use crate::locale::Locale;
+use crate::util::direction;
+use crate::util::format;
+use crate::view::{MonthView, MonthsView, YearsView};
+use crate::Error;
+
+// MODEL
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SelectionMode {
+    Single,
+    Range,
+    /// Any number of dates, each toggled on and off by clicking it.
+    Multiple,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Level {
+    Days,
+    Months,
+    Years,
+}
+
+pub struct Model {
+    level: Level,
+    year: i32,
+    month: u32,
+    mode: SelectionMode,
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+    dates: BTreeSet<NaiveDate>,
+    focused: Option<NaiveDate>,
+    min_date: Option<NaiveDate>,
+    max_date: Option<NaiveDate>,
+    disabled: Option<Rc<dyn Fn(NaiveDate) -> bool>>,
+    today: Option<NaiveDate>,
+    first_weekday: Option<Weekday>,
+    show_week_numbers: bool,
+    navigation_labels: HashMap<Level, (String, String)>,
+    locale: Locale,
+    calendar: Calendar,
+    container: ElRef<web_sys::HtmlElement>,
+}
+
+impl Model {
+    // Constructor
+
+    pub fn new(year: i32, month: u32) -> Self {
+        Model {
+            level: Level::Days,
+            year,
+            month,
+            mode: SelectionMode::Single,
+            start: None,
+            end: None,
+            dates: BTreeSet::new(),
+            focused: None,
+            min_date: None,
+            max_date: None,
+            disabled: None,
+            today: None,
+            first_weekday: None,
+            show_week_numbers: false,
+            navigation_labels: HashMap::new(),
+            locale: Locale::default(),
+            calendar: Calendar::Gregorian,
+            container: ElRef::default(),
+        }
+    }
+
+    /// Like `new`, but fails if the month doesn't exist.
+    pub fn try_new(year: i32, month: u32) -> Result<Self, Error> {
+        MonthGrid::try_new(year, month)?;
+
+        Ok(Model::new(year, month))
+    }
+
+    // Builder functions
+
+    pub fn with_mode(mut self, mode: SelectionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn with_selected(mut self, date: NaiveDate) -> Self {
+        self.start = Some(date);
+        self.end = None;
+        self.dates = iter::once(date).collect();
+        self.level = Level::Days;
+        self.show_date(date);
+        self
+    }
+
+    /// Selects the dates in multiple selection mode, showing the month of the earliest.
+    pub fn with_selected_dates(mut self, dates: impl IntoIterator<Item = NaiveDate>) -> Self {
+        self.dates = dates.into_iter().collect();
+        if let Some(&date) = self.dates.iter().next() {
+            self.level = Level::Days;
+            self.show_date(date);
+        }
+        self
+    }
+
+    pub fn with_min_date(mut self, date: NaiveDate) -> Self {
+        self.min_date = Some(date);
+        self
+    }
+
+    pub fn with_max_date(mut self, date: NaiveDate) -> Self {
+        self.max_date = Some(date);
+        self
+    }
+
+    /// Sets both the minimum and maximum date, failing if the minimum is after the maximum.
+    pub fn try_with_date_range(self, min: NaiveDate, max: NaiveDate) -> Result<Self, Error> {
+        Error::check_date_range(min, max)?;
+
+        Ok(self.with_min_date(min).with_max_date(max))
+    }
+
+    pub fn with_disabled(mut self, predicate: impl Fn(NaiveDate) -> bool + 'static) -> Self {
+        self.disabled = Some(Rc::new(predicate));
+        self
+    }
+
+    pub fn with_today(mut self, date: NaiveDate) -> Self {
+        self.today = Some(date);
+        self
+    }
+
+    pub fn with_first_weekday(mut self, weekday: Weekday) -> Self {
+        self.first_weekday = Some(weekday);
+        self
+    }
+
+    pub fn with_locale(mut self, locale: impl Into<Locale>) -> Self {
+        self.locale = locale.into();
+        self
+    }
+
+    /// Like `with_locale`, but fails if the locale isn't a well-formed language tag.
+    pub fn try_with_locale(self, locale: impl AsRef<str>) -> Result<Self, Error> {
+        Ok(self.with_locale(Locale::new(locale.as_ref())?))
+    }
+
+    /// Switches to the given calendar, keeping the current month in view. The year and month
+    /// passed to `new`, and returned by the accessors, are in the current calendar.
+    pub fn with_calendar(mut self, calendar: Calendar) -> Self {
+        if let Some(first_of_month) = self.calendar.first_of_month(self.year, self.month) {
+            self.calendar = calendar;
+            self.show_date(first_of_month);
+        } else {
+            self.calendar = calendar;
+        }
+        self
+    }
+
+    pub fn show_week_numbers(mut self) -> Self {
+        self.show_week_numbers = true;
+        self
+    }
+
+    /// Labels the previous and next buttons of a level for assistive technologies, e.g. in the
+    /// language of the locale. As they move by a month, a year and a decade respectively, the
+    /// defaults are "Previous month", "Previous year" and "Previous decade", and likewise "Next".
+    pub fn with_navigation_labels(
+        mut self,
+        level: Level,
+        previous: impl Into<String>,
+        next: impl Into<String>,
+    ) -> Self {
+        self.navigation_labels
+            .insert(level, (previous.into(), next.into()));
+        self
+    }
+
+    // Accessors
+
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    pub fn mode(&self) -> SelectionMode {
+        self.mode
+    }
+
+    pub fn focused(&self) -> Option<NaiveDate> {
+        self.focused
+    }
+
+    pub fn calendar(&self) -> Calendar {
+        self.calendar
+    }
+
+    fn show_date(&mut self, date: NaiveDate) {
+        let date = self.calendar.from_date(date);
+        self.year = date.year;
+        self.month = date.month;
+    }
+
+    // The month in view, which also decides which dates are disabled
+    fn grid(&self) -> MonthGrid {
+        let (start, end) = self.selection();
+        let mut grid = MonthGrid::new(self.year, self.month)
+            .with_calendar(self.calendar)
+            .with_locale(self.locale.clone())
+            .with_selection(match self.mode {
+                SelectionMode::Multiple => Selection::from_dates(self.dates.iter().copied()),
+                _ => Selection::from_bounds(start, end),
+            });
+
+        if let Some(first_weekday) = self.first_weekday {
+            grid = grid.with_first_weekday(first_weekday);
+        }
+        if let Some(today) = self.today {
+            grid = grid.with_today(today);
+        }
+        if let Some(min_date) = self.min_date {
+            grid = grid.with_min_date(min_date);
+        }
+        if let Some(max_date) = self.max_date {
+            grid = grid.with_max_date(max_date);
+        }
+        if let Some(disabled) = self.disabled.clone() {
+            grid = grid.with_disabled(move |date| disabled(date));
+        }
+        grid
+    }
+
+    fn is_disabled(&self, date: NaiveDate) -> bool {
+        self.grid().is_disabled(date)
+    }
+
+    /// The current selection, ordered so that the start is never after the end.
+    /// The end is `None` while a range selection is in progress. In multiple selection mode,
+    /// these are the earliest and latest of the selected dates.
+    pub fn selection(&self) -> (Option<NaiveDate>, Option<NaiveDate>) {
+        if self.mode == SelectionMode::Multiple {
+            return (
+                self.dates.iter().next().copied(),
+                self.dates.iter().next_back().copied(),
+            );
+        }
+
+        match (self.start, self.end) {
+            (Some(start), Some(end)) if start > end => (Some(end), Some(start)),
+            selection => selection,
+        }
+    }
+
+    /// The selected dates in multiple selection mode, in order.
+    pub fn selected_dates(&self) -> &BTreeSet<NaiveDate> {
+        &self.dates
+    }
+}
+
+// UPDATE
+
+#[derive(Clone)]
+pub enum Msg {
+    SelectDate(NaiveDate),
+    Focus(NaiveDate),
+    SelectMonth(u32),
+    FocusMonth(u32),
+    SelectYear(i32),
+    FocusYear(i32),
+    Navigate(i32, u32),
+    ZoomOut,
+    Clear,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Output {
+    /// The first date of a range has been picked, and the picker is waiting for the second.
+    SelectionStarted(NaiveDate),
+    /// A complete selection has been made. Start and end are equal in single selection mode.
+    SelectionCommitted(NaiveDate, NaiveDate),
+    /// A range was completed, but was rejected because it contains disabled days. The start of
+    /// the range is still pending.
+    SelectionRejected(NaiveDate, NaiveDate),
+    /// A date has been added to the selection in multiple selection mode.
+    DateSelected(NaiveDate),
+    /// A date has been removed from the selection in multiple selection mode.
+    DateDeselected(NaiveDate),
+    SelectionCleared,
+}
+
+pub fn update<Ms: 'static>(
+    msg: Msg,
+    model: &mut Model,
+    orders: &mut impl Orders<Ms>,
+) -> Option<Output> {
+    match msg {
+        Msg::SelectDate(date) if model.is_disabled(date) => None,
+        Msg::SelectDate(date) => {
+            model.focused = Some(date);
+            Some(select_date(model, date))
+        }
+        Msg::Focus(date) => {
+            model.focused = Some(date);
+            model.show_date(date);
+
+            let container = model.container.clone();
+            orders.after_next_render(move |_| focus_tabbable(&container));
+            None
+        }
+        Msg::SelectMonth(month) => {
+            model.month = month;
+            model.level = Level::Days;
+            None
+        }
+        Msg::FocusMonth(month) => {
+            model.month = month;
+
+            let container = model.container.clone();
+            orders.after_next_render(move |_| focus_tabbable(&container));
+            None
+        }
+        Msg::SelectYear(year) => {
+            model.year = year;
+            model.month = model.month.min(model.calendar.months_in_year(year));
+            model.level = Level::Months;
+            None
+        }
+        Msg::FocusYear(year) => {
+            model.year = year;
+            model.month = model.month.min(model.calendar.months_in_year(year));
+
+            let container = model.container.clone();
+            orders.after_next_render(move |_| focus_tabbable(&container));
+            None
+        }
+        Msg::Navigate(year, month) => {
+            model.year = year;
+            model.month = month.min(model.calendar.months_in_year(year));
+            None
+        }
+        Msg::ZoomOut => {
+            model.level = match model.level {
+                Level::Days => Level::Months,
+                Level::Months | Level::Years => Level::Years,
+            };
+            None
+        }
+        Msg::Clear => {
+            model.start = None;
+            model.end = None;
+            model.dates.clear();
+            Some(Output::SelectionCleared)
+        }
+    }
+}
+
+fn select_date(model: &mut Model, date: NaiveDate) -> Output {
+    match (model.mode, model.start, model.end) {
+        (SelectionMode::Single, _, _) => {
+            model.start = Some(date);
+            model.end = None;
+            Output::SelectionCommitted(date, date)
+        }
+        (SelectionMode::Range, Some(start), None) => {
+            let (start, end) = (start.min(date), start.max(date));
+            let grid = model.grid();
+
+            if start
+                .iter_days()
+                .take_while(|&day| day <= end)
+                .any(|day| grid.is_disabled(day))
+            {
+                Output::SelectionRejected(start, end)
+            } else {
+                model.end = Some(date);
+                Output::SelectionCommitted(start, end)
+            }
+        }
+        (SelectionMode::Range, _, _) => {
+            model.start = Some(date);
+            model.end = None;
+            Output::SelectionStarted(date)
+        }
+        (SelectionMode::Multiple, _, _) => {
+            if model.dates.remove(&date) {
+                Output::DateDeselected(date)
+            } else {
+                model.dates.insert(date);
+                Output::DateSelected(date)
+            }
+        }
+    }
+}
+
+// Focuses the day, month or year that can be tabbed to, whichever level is shown
+fn focus_tabbable(container: &ElRef<web_sys::HtmlElement>) {
+    let element = container
+        .get()
+        .and_then(|container| container.query_selector("[tabindex='0']").ok())
+        .flatten()
+        .and_then(|element| element.dyn_into::<web_sys::HtmlElement>().ok());
+
+    if let Some(element) = element {
+        element.focus().ok();
+    }
+}
+
+// VIEW
+
+pub fn view(model: &Model) -> Node<Msg> {
+    div![
+        C!["seed-calendar-date-picker"],
+        attrs! { At::Dir => direction::for_locale(model.locale.as_str()).as_str() },
+        el_ref(&model.container),
+        match model.level {
+            Level::Days => view_days(model),
+            Level::Months => view_months(model),
+            Level::Years => view_years(model),
+        }
+    ]
+}
+
+fn view_days(model: &Model) -> Vec<Node<Msg>> {
+    let mut month_view = MonthView::from_grid(model.grid())
+        .show_header()
+        .show_weekdays()
+        .on_click(Msg::SelectDate)
+        .on_focus_change(Msg::Focus)
+        .on_navigate(Msg::Navigate)
+        .on_title_click(|| Msg::ZoomOut);
+
+    if let Some((previous, next)) = model.navigation_labels.get(&Level::Days) {
+        month_view = month_view.with_navigation_labels(previous, next);
+    }
+    if let Some(focused) = model.focused {
+        month_view = month_view.with_focused(focused);
+    }
+
+    vec![if model.show_week_numbers {
+        month_view.show_week_numbers().into_node()
+    } else {
+        month_view.into_node()
+    }]
+}
+
+fn view_months(model: &Model) -> Vec<Node<Msg>> {
+    // The month in view is only focused, as it needn't be selected
+    let selected = match model.selection() {
+        (Some(start), _) if model.calendar.from_date(start).year == model.year => {
+            Some(model.calendar.from_date(start).month)
+        }
+        _ => None,
+    };
+
+    vec![
+        view_header(
+            model,
+            Level::Months,
+            format::year(model.year, model.calendar, model.locale.as_str()),
+            Some(Msg::ZoomOut),
+            Msg::Navigate(model.year - 1, model.month),
+            Msg::Navigate(model.year + 1, model.month),
+        ),
+        {
+            let mut months_view = MonthsView::new()
+                .with_locale(model.locale.clone())
+                .with_calendar(model.calendar)
+                .with_year(model.year)
+                .with_focused(model.month)
+                .on_click(Msg::SelectMonth)
+                .on_focus_change(Msg::FocusMonth);
+
+            if let Some(selected) = selected {
+                months_view = months_view.with_selected(selected);
+            }
+
+            match model.today {
+                Some(today) => months_view.with_today(today).into_node(),
+                None => months_view.into_node(),
+            }
+        },
+    ]
+}
+
+fn view_years(model: &Model) -> Vec<Node<Msg>> {
+    let decade = model.year - model.year.rem_euclid(10);
+    let selected = model
+        .selection()
+        .0
+        .map(|start| model.calendar.from_date(start).year);
+
+    vec![
+        view_header(
+            model,
+            Level::Years,
+            format!(
+                "{} – {}",
+                format::year(decade, model.calendar, model.locale.as_str()),
+                format::year(decade + 9, model.calendar, model.locale.as_str())
+            ),
+            None,
+            Msg::Navigate(model.year - 10, model.month),
+            Msg::Navigate(model.year + 10, model.month),
+        ),
+        {
+            let mut years_view = YearsView::decade_from(decade)
+                .with_locale(model.locale.clone())
+                .with_calendar(model.calendar)
+                .with_focused(model.year)
+                .on_click(Msg::SelectYear)
+                .on_focus_change(Msg::FocusYear);
+
+            if let Some(selected) = selected {
+                years_view = years_view.with_selected(selected);
+            }
+
+            match model.today {
+                Some(today) => years_view.with_today(today).into_node(),
+                None => years_view.into_node(),
+            }
+        },
+    ]
+}
+
+fn view_header(
+    model: &Model,
+    level: Level,
+    title: String,
+    on_title_click: Option<Msg>,
+    previous: Msg,
+    next: Msg,
+) -> Node<Msg> {
+    let direction = direction::for_locale(model.locale.as_str());
+    let (previous_label, next_label) = match (model.navigation_labels.get(&level), level) {
+        (Some((previous, next)), _) => (previous.as_str(), next.as_str()),
+        (None, Level::Years) => ("Previous decade", "Next decade"),
+        (None, _) => ("Previous year", "Next year"),
+    };
+
+    div![
+        C!["header"],
+        button![
+            C!["previous"],
+            attrs! { At::AriaLabel => previous_label },
+            ev(Ev::Click, |_| previous),
+            direction.previous_arrow()
+        ],
+        match on_title_click {
+            Some(msg) => button![C!["title"], ev(Ev::Click, |_| msg), title],
+            None => span![C!["title"], title],
+        },
+        button![
+            C!["next"],
+            attrs! { At::AriaLabel => next_label },
+            ev(Ev::Click, |_| next),
+            direction.next_arrow()
+        ],
+    ]
+}
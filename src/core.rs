@@ -0,0 +1,12 @@
+//! Framework-independent calendar logic: the layout of a month as a grid of weeks and days, how
+//! weeks are numbered, and how a selection of dates intersects them. The views in `crate::view`
+//! are Seed renderers over these, which can equally be rendered by other means, or tested
+//! natively.
+
+mod grid;
+mod selection;
+mod week;
+
+pub use grid::{DayState, GridDay, GridWeek, MonthGrid};
+pub use selection::{Intersection, Selection};
+pub use week::{start_of_week, Week, WeekNumbering, WeekRule};
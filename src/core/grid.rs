@@ -0,0 +1,333 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::iter;
+use std::rc::Rc;
+
+use super::{start_of_week, Intersection, Selection, Week, WeekNumbering, WeekRule};
+use crate::calendar::Calendar;
+use crate::locale::Locale;
+use crate::util::week_info::{self, WeekInfo};
+use crate::Error;
+
+// Six rows cover any month of up to 31 days, whatever weekday it starts on
+const WEEKS: usize = 6;
+
+/// The layout of a month as the rows of a calendar, along with the state of each day. Weeks
+/// start on the first weekday of the locale unless set explicitly, and always span six rows, so
+/// the grid doesn't change height between months.
+#[derive(Clone)]
+pub struct MonthGrid {
+    year: i32,
+    month: u32,
+    calendar: Calendar,
+    selection: Selection,
+    min_date: Option<NaiveDate>,
+    max_date: Option<NaiveDate>,
+    disabled: Option<Rc<dyn Fn(NaiveDate) -> bool>>,
+    today: Option<NaiveDate>,
+    first_weekday: Option<Weekday>,
+    week_numbering: Option<WeekNumbering>,
+    locale: Locale,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GridWeek {
+    pub week: Week,
+    pub days: Vec<GridDay>,
+    pub selection: Option<Intersection>,
+    /// Whether today is in the week, and in the month, as for `DayState::today`.
+    pub contains_today: bool,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GridDay {
+    pub date: NaiveDate,
+    /// The day of the month in the grid's calendar.
+    pub day: u32,
+    pub state: DayState,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DayState {
+    pub in_month: bool,
+    pub selection: Option<Intersection>,
+    pub today: bool,
+    pub disabled: bool,
+    pub weekend: bool,
+}
+
+impl DayState {
+    pub fn is_selected(&self) -> bool {
+        self.selection.is_some()
+    }
+}
+
+impl MonthGrid {
+    // Constructor
+
+    pub fn new(year: i32, month: u32) -> Self {
+        MonthGrid {
+            year,
+            month,
+            calendar: Calendar::Gregorian,
+            selection: Selection::None,
+            min_date: None,
+            max_date: None,
+            disabled: None,
+            today: None,
+            first_weekday: None,
+            week_numbering: None,
+            locale: Locale::default(),
+        }
+    }
+
+    /// Like `new`, but fails if the month doesn't exist.
+    pub fn try_new(year: i32, month: u32) -> Result<Self, Error> {
+        MonthGrid::new(year, month).validated()
+    }
+
+    // Builder functions
+
+    /// Lays out the month in the given calendar, which the year and month are then in too.
+    pub fn with_calendar(mut self, calendar: Calendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// Like `with_calendar`, but fails if the month doesn't exist in the calendar.
+    pub fn try_with_calendar(self, calendar: Calendar) -> Result<Self, Error> {
+        self.with_calendar(calendar).validated()
+    }
+
+    pub fn with_selection(mut self, selection: Selection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    pub fn with_min_date(mut self, date: NaiveDate) -> Self {
+        self.min_date = Some(date);
+        self
+    }
+
+    pub fn with_max_date(mut self, date: NaiveDate) -> Self {
+        self.max_date = Some(date);
+        self
+    }
+
+    /// Sets both the minimum and maximum date, failing if the minimum is after the maximum.
+    pub fn try_with_date_range(self, min: NaiveDate, max: NaiveDate) -> Result<Self, Error> {
+        Error::check_date_range(min, max)?;
+
+        Ok(self.with_min_date(min).with_max_date(max))
+    }
+
+    pub fn with_disabled(mut self, predicate: impl Fn(NaiveDate) -> bool + 'static) -> Self {
+        self.disabled = Some(Rc::new(predicate));
+        self
+    }
+
+    pub fn with_today(mut self, date: NaiveDate) -> Self {
+        self.today = Some(date);
+        self
+    }
+
+    pub fn with_first_weekday(mut self, weekday: Weekday) -> Self {
+        self.first_weekday = Some(weekday);
+        self
+    }
+
+    /// Numbers the weeks by the given scheme, rather than by the conventions of the locale. The
+    /// scheme decides the first weekday too, over `with_first_weekday`.
+    pub fn with_week_numbering(mut self, numbering: WeekNumbering) -> Self {
+        self.week_numbering = Some(numbering);
+        self
+    }
+
+    /// The locale decides the first weekday, unless set explicitly, and which days are weekend.
+    pub fn with_locale(mut self, locale: impl Into<Locale>) -> Self {
+        self.locale = locale.into();
+        self
+    }
+
+    /// Like `with_locale`, but fails if the locale isn't a well-formed language tag.
+    pub fn try_with_locale(self, locale: impl AsRef<str>) -> Result<Self, Error> {
+        Ok(self.with_locale(Locale::new(locale.as_ref())?))
+    }
+
+    // Queries
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    pub fn calendar(&self) -> Calendar {
+        self.calendar
+    }
+
+    pub fn selection(&self) -> &Selection {
+        &self.selection
+    }
+
+    pub fn locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    pub fn first_weekday(&self) -> Weekday {
+        match (self.week_numbering, self.first_weekday) {
+            (Some(numbering), _) => numbering.rule().first_weekday(),
+            (None, Some(weekday)) => weekday,
+            (None, None) => week_info::for_locale(self.locale.as_str()).first_day,
+        }
+    }
+
+    /// How the rows are numbered: by the week numbering scheme if set, and otherwise like the ISO
+    /// weeks they overlap most, whichever the first weekday. See `WeekRule::iso_aligned`.
+    pub fn week_rule(&self) -> WeekRule {
+        self.rule(&week_info::for_locale(self.locale.as_str()))
+    }
+
+    /// The weekdays of the columns, in order.
+    pub fn weekdays(&self) -> Vec<Weekday> {
+        iter::successors(Some(self.first_weekday()), |weekday| Some(weekday.succ()))
+            .take(7)
+            .collect()
+    }
+
+    pub fn weeks(&self) -> Vec<GridWeek> {
+        let week_info = week_info::for_locale(self.locale.as_str());
+        let rule = self.rule(&week_info);
+
+        start_of_week(self.first_of_month(), rule.first_weekday())
+            .iter_weeks()
+            .take(WEEKS)
+            .map(|start| {
+                let end = start + Duration::days(6);
+                let days: Vec<GridDay> = start
+                    .iter_days()
+                    .take(7)
+                    .map(|date| GridDay {
+                        date,
+                        day: self.calendar.from_date(date).day,
+                        state: self.state(date, &week_info),
+                    })
+                    .collect();
+
+                GridWeek {
+                    week: rule.week(start),
+                    contains_today: days.iter().any(|day| day.state.today),
+                    days,
+                    selection: self.selection.intersects_range(start, end),
+                }
+            })
+            .collect()
+    }
+
+    /// The state of a date, which needn't be in the grid.
+    pub fn day_state(&self, date: NaiveDate) -> DayState {
+        self.state(date, &week_info::for_locale(self.locale.as_str()))
+    }
+
+    pub fn is_in_month(&self, date: NaiveDate) -> bool {
+        let date = self.calendar.from_date(date);
+
+        date.year == self.year && date.month == self.month
+    }
+
+    pub fn is_disabled(&self, date: NaiveDate) -> bool {
+        matches!(self.min_date, Some(min) if date < min)
+            || matches!(self.max_date, Some(max) if date > max)
+            || matches!(&self.disabled, Some(disabled) if disabled(date))
+    }
+
+    /// Whether any selected date is disabled, e.g. after the selectable dates have changed.
+    pub fn has_invalid_selection(&self) -> bool {
+        self.selection.includes_any(|date| self.is_disabled(date))
+    }
+
+    /// Checks that the month exists in the calendar. Other queries panic if it doesn't.
+    pub fn validate(&self) -> Result<(), Error> {
+        Error::check_month(self.calendar, self.year, self.month).map(|_| ())
+    }
+
+    /// # Panics
+    ///
+    /// If the month doesn't exist in the calendar.
+    pub fn first_of_month(&self) -> NaiveDate {
+        self.calendar
+            .first_of_month(self.year, self.month)
+            .expect("invalid month")
+    }
+
+    pub fn last_of_month(&self) -> NaiveDate {
+        self.first_of_month()
+            .iter_days()
+            .take_while(|date| self.is_in_month(*date))
+            .last()
+            .unwrap()
+    }
+
+    /// The previous month, unless it's entirely before the minimum date.
+    pub fn previous_month(&self) -> Option<(i32, u32)> {
+        let first_of_month = self.first_of_month();
+
+        (!matches!(self.min_date, Some(min) if min >= first_of_month))
+            .then(|| self.calendar.previous_month(self.year, self.month))
+    }
+
+    /// The next month, unless it's entirely after the maximum date.
+    pub fn next_month(&self) -> Option<(i32, u32)> {
+        let (year, month) = self.calendar.next_month(self.year, self.month);
+        let first_of_next_month = self.calendar.first_of_month(year, month)?;
+
+        (!matches!(self.max_date, Some(max) if max < first_of_next_month)).then(|| (year, month))
+    }
+
+    /// The one day in the grid that can be reached with the tab key, following the roving
+    /// tabindex pattern: the focused date, the first selected date, today, or the first enabled
+    /// day of the month, whichever is first to be enabled and in the month.
+    pub fn tabbable_date(&self, focused: Option<NaiveDate>) -> NaiveDate {
+        let first_of_month = self.first_of_month();
+        let is_tabbable = |date: &NaiveDate| self.is_in_month(*date) && !self.is_disabled(*date);
+
+        focused
+            .filter(is_tabbable)
+            .or_else(|| self.selection.start().filter(is_tabbable))
+            .or_else(|| self.today.filter(is_tabbable))
+            .or_else(|| {
+                first_of_month
+                    .iter_days()
+                    .take_while(|date| self.is_in_month(*date))
+                    .find(is_tabbable)
+            })
+            .unwrap_or(first_of_month)
+    }
+
+    // State helpers
+
+    fn validated(self) -> Result<Self, Error> {
+        self.validate()?;
+        Ok(self)
+    }
+
+    fn rule(&self, week_info: &WeekInfo) -> WeekRule {
+        self.week_numbering.map_or_else(
+            || WeekRule::iso_aligned(self.first_weekday.unwrap_or(week_info.first_day)),
+            WeekNumbering::rule,
+        )
+    }
+
+    fn state(&self, date: NaiveDate, week_info: &WeekInfo) -> DayState {
+        let in_month = self.is_in_month(date);
+
+        DayState {
+            in_month,
+            selection: self.selection.intersects(date),
+            today: self.today == Some(date) && in_month,
+            disabled: self.is_disabled(date),
+            weekend: week_info.is_weekend(date.weekday()),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use chrono::NaiveDate;
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Selection {
+    None,
+    Single(NaiveDate),
+    Range(NaiveDate, NaiveDate),
+    /// Any number of dates, not necessarily adjacent.
+    Multiple(BTreeSet<NaiveDate>),
+}
+
+/// How a selection covers a date, or a range of dates such as a week.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Intersection {
+    Start,
+    Inside,
+    End,
+    All,
+}
+
+impl Selection {
+    /// The dates from `start` to `end`, given in either order. Equal dates select a single date.
+    pub fn between(start: NaiveDate, end: NaiveDate) -> Self {
+        match start.cmp(&end) {
+            Ordering::Equal => Selection::Single(start),
+            Ordering::Greater => Selection::Range(end, start),
+            Ordering::Less => Selection::Range(start, end),
+        }
+    }
+
+    /// A selection from its ends, either of which may not have been picked yet.
+    pub fn from_bounds(start: Option<NaiveDate>, end: Option<NaiveDate>) -> Self {
+        match (start, end) {
+            (None, None) => Selection::None,
+            (Some(date), None) | (None, Some(date)) => Selection::Single(date),
+            (Some(start), Some(end)) => Selection::between(start, end),
+        }
+    }
+
+    /// The given dates, in any order and with any duplicates. No dates select none.
+    pub fn from_dates(dates: impl IntoIterator<Item = NaiveDate>) -> Self {
+        let dates: BTreeSet<NaiveDate> = dates.into_iter().collect();
+
+        if dates.is_empty() {
+            Selection::None
+        } else {
+            Selection::Multiple(dates)
+        }
+    }
+
+    /// The earliest selected date.
+    pub fn start(&self) -> Option<NaiveDate> {
+        match *self {
+            Selection::None => None,
+            Selection::Single(date) | Selection::Range(date, _) => Some(date),
+            Selection::Multiple(ref dates) => dates.iter().next().copied(),
+        }
+    }
+
+    /// Each of multiple dates is selected on its own, so they intersect a date entirely.
+    pub fn intersects(&self, date: NaiveDate) -> Option<Intersection> {
+        use Intersection::*;
+        Some(match *self {
+            Selection::Single(selected) if selected == date => All,
+            Selection::Multiple(ref dates) if dates.contains(&date) => All,
+            Selection::Range(start, _) if start == date => Start,
+            Selection::Range(_, end) if end == date => End,
+            Selection::Range(start, end) if date > start && date < end => Inside,
+            _ => return None,
+        })
+    }
+
+    /// Multiple dates intersect a range entirely if any of them are in it, like a single date.
+    pub fn intersects_range(&self, start: NaiveDate, end: NaiveDate) -> Option<Intersection> {
+        use Intersection::*;
+        Some(match *self {
+            Selection::Single(selected) if selected >= start && selected <= end => All,
+            Selection::Multiple(ref dates) if dates.range(start..=end).next().is_some() => All,
+            Selection::Range(sel_start, sel_end) if sel_start == start && sel_end == end => All,
+            Selection::Range(sel_start, _) if sel_start >= start && sel_start <= end => Start,
+            Selection::Range(_, sel_end) if sel_end >= start && sel_end <= end => End,
+            Selection::Range(sel_start, sel_end) if sel_start <= start && sel_end >= end => Inside,
+            _ => return None,
+        })
+    }
+
+    pub fn includes_any(&self, predicate: impl Fn(NaiveDate) -> bool) -> bool {
+        match *self {
+            Selection::None => false,
+            Selection::Single(date) => predicate(date),
+            Selection::Range(start, end) => start
+                .iter_days()
+                .take_while(|&date| date <= end)
+                .any(predicate),
+            Selection::Multiple(ref dates) => dates.iter().any(|&date| predicate(date)),
+        }
+    }
+}
+
+impl Default for Selection {
+    fn default() -> Self {
+        Selection::None
+    }
+}
@@ -0,0 +1,248 @@
+use chrono::{Datelike, Duration, IsoWeek, NaiveDate, Weekday};
+use std::cmp::Ordering;
+
+/// How weeks are laid out and numbered: the weekday they start on, and how many days of the
+/// first week of a year must fall in that year. Week 1 is the first week with at least that many
+/// days, and the weeks before it belong to the previous year.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WeekRule {
+    first_weekday: Weekday,
+    minimal_days: u32,
+}
+
+/// A week of a week-numbering year, according to a `WeekRule`. Its year can differ from the
+/// year of some of its days, e.g. by the ISO rule, 2021-01-01 is in week 53 of 2020.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Week {
+    year: i32,
+    number: u32,
+    first_day: NaiveDate,
+    rule: WeekRule,
+}
+
+/// The common schemes for numbering weeks. Each decides the weekday weeks start on too, so the
+/// rows of a month are always whole numbered weeks.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WeekNumbering {
+    /// ISO 8601, as in most of Europe: weeks start on Monday, and week 1 is the one with the
+    /// year's first Thursday. The first days of January can be in week 52 or 53 of the previous
+    /// year, e.g. 2021-01-03 is in week 53 of 2020.
+    Iso,
+    /// As in the US and Canada: weeks start on Sunday, and week 1 is the one with January 1st.
+    /// The last days of December can be in week 1 of the next year, e.g. 2020-12-27 is in week 1
+    /// of 2021.
+    Us,
+    /// As in much of the Middle East: weeks start on Saturday, and week 1 is the one with
+    /// January 1st. The last days of December can be in week 53, e.g. 2021-12-31 is in week 53
+    /// of 2021, as 2022 starts on a Saturday.
+    MiddleEast,
+    Custom(WeekRule),
+}
+
+impl WeekNumbering {
+    pub fn rule(self) -> WeekRule {
+        match self {
+            WeekNumbering::Iso => WeekRule::ISO,
+            WeekNumbering::Us => WeekRule::new(Weekday::Sun, 1),
+            WeekNumbering::MiddleEast => WeekRule::new(Weekday::Sat, 1),
+            WeekNumbering::Custom(rule) => rule,
+        }
+    }
+}
+
+impl From<WeekRule> for WeekNumbering {
+    fn from(rule: WeekRule) -> Self {
+        WeekNumbering::Custom(rule)
+    }
+}
+
+impl WeekRule {
+    /// ISO 8601 weeks start on Monday, and week 1 is the one with the year's first Thursday.
+    pub const ISO: WeekRule = WeekRule {
+        first_weekday: Weekday::Mon,
+        minimal_days: 4,
+    };
+
+    /// The minimal days are clamped to a week, from 1, where week 1 is the one with January 1st,
+    /// to 7, where it's the first week entirely in the year.
+    pub fn new(first_weekday: Weekday, minimal_days: u32) -> Self {
+        WeekRule {
+            first_weekday,
+            minimal_days: minimal_days.clamp(1, 7),
+        }
+    }
+
+    /// Weeks starting on the given weekday, each numbered like the ISO week that most of its days
+    /// are in. Month views number their rows by this rule unless given a `WeekNumbering`, e.g.
+    /// with Sunday as the first weekday, 2021-01-03 to 2021-01-09 is week 1, as six of its days
+    /// are in ISO week 1.
+    pub fn iso_aligned(first_weekday: Weekday) -> Self {
+        // Week 1 then has at least four days of ISO week 1, which starts on the Monday on or
+        // before January 4th
+        let minimal_days = match first_weekday {
+            Weekday::Mon => 4,
+            Weekday::Tue => 5,
+            Weekday::Wed => 6,
+            Weekday::Thu => 7,
+            Weekday::Fri => 1,
+            Weekday::Sat => 2,
+            Weekday::Sun => 3,
+        };
+
+        WeekRule::new(first_weekday, minimal_days)
+    }
+
+    pub fn first_weekday(self) -> Weekday {
+        self.first_weekday
+    }
+
+    pub fn minimal_days(self) -> u32 {
+        self.minimal_days
+    }
+
+    /// The week the date falls in. Near the ends of the range of dates, where the neighbouring
+    /// year doesn't exist, weeks are counted from the nearest year that does.
+    pub fn week(self, date: NaiveDate) -> Week {
+        let first_day = start_of_week(date, self.first_weekday);
+        let (year, first_day_of_year) = [date.year() + 1, date.year(), date.year() - 1]
+            .iter()
+            .filter_map(|&year| Some((year, self.first_day_of_year(year)?)))
+            .find(|&(_, first_day_of_year)| first_day_of_year <= first_day)
+            .unwrap_or((date.year(), first_day));
+
+        Week {
+            year,
+            number: ((first_day - first_day_of_year).num_days() / 7) as u32 + 1,
+            first_day,
+            rule: self,
+        }
+    }
+
+    // The first day of week 1, which is in the week of the day that completes the minimal days,
+    // or `None` outside the range of dates
+    fn first_day_of_year(self, year: i32) -> Option<NaiveDate> {
+        let date = NaiveDate::from_ymd_opt(year, 1, self.minimal_days)?;
+        let offset = helpers::days_since_start_of_week(date, self.first_weekday);
+
+        date.checked_sub_signed(Duration::days(offset as i64))
+    }
+}
+
+impl Default for WeekRule {
+    fn default() -> Self {
+        WeekRule::ISO
+    }
+}
+
+impl Week {
+    /// The week with the given number of a week-numbering year, or `None` if the year has no
+    /// such week.
+    pub fn new(year: i32, number: u32, rule: WeekRule) -> Option<Week> {
+        if number < 1 {
+            return None;
+        }
+
+        let first_day = rule
+            .first_day_of_year(year)?
+            .checked_add_signed(Duration::weeks(number as i64 - 1))?;
+        Some(rule.week(first_day)).filter(|week| week.year == year)
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    pub fn rule(&self) -> WeekRule {
+        self.rule
+    }
+
+    pub fn first_day(&self) -> NaiveDate {
+        self.first_day
+    }
+
+    pub fn last_day(&self) -> NaiveDate {
+        self.first_day + Duration::days(6)
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = NaiveDate> {
+        self.first_day.iter_days().take(7)
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        date >= self.first_day && date - self.first_day < Duration::weeks(1)
+    }
+
+    pub fn pred(&self) -> Week {
+        self.rule.week(self.first_day - Duration::weeks(1))
+    }
+
+    pub fn succ(&self) -> Week {
+        self.rule.week(self.first_day + Duration::weeks(1))
+    }
+}
+
+impl From<IsoWeek> for Week {
+    fn from(week: IsoWeek) -> Self {
+        WeekRule::ISO.week(NaiveDate::from_isoywd(
+            week.year(),
+            week.week(),
+            Weekday::Mon,
+        ))
+    }
+}
+
+// Weeks are ordered by their days, and only then by their rules, to be consistent with `Eq`
+impl Ord for Week {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let key = |week: &Week| {
+            (
+                week.first_day,
+                week.rule.first_weekday.num_days_from_monday(),
+                week.rule.minimal_days,
+            )
+        };
+
+        key(self).cmp(&key(other))
+    }
+}
+
+impl PartialOrd for Week {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The first day of the week the date falls in.
+pub fn start_of_week(date: NaiveDate, first_weekday: Weekday) -> NaiveDate {
+    let offset = helpers::days_since_start_of_week(date, first_weekday);
+
+    date - Duration::days(offset as i64)
+}
+
+// Helpers
+
+mod helpers {
+    use chrono::{Datelike, NaiveDate, Weekday};
+
+    pub fn days_since_earliest_weekday(weekday: Weekday) -> u32 {
+        match weekday {
+            Weekday::Sat => 0,
+            Weekday::Sun => 1,
+            Weekday::Mon => 2,
+            Weekday::Tue => 3,
+            Weekday::Wed => 4,
+            Weekday::Thu => 5,
+            Weekday::Fri => 6,
+        }
+    }
+
+    pub fn days_since_start_of_week(date: NaiveDate, first_weekday: Weekday) -> i32 {
+        (days_since_earliest_weekday(date.weekday()) as i32
+            - days_since_earliest_weekday(first_weekday) as i32)
+            .rem_euclid(7)
+    }
+}
@@ -0,0 +1,83 @@
+use chrono::{Duration, NaiveDate};
+use std::fmt;
+
+/// The ways a calendar can be misconfigured, as reported by the `try_` constructors and
+/// builders. The infallible counterparts don't panic on these either; rendering degrades instead,
+/// e.g. to an empty view for a month that doesn't exist, or bundled locale data when `Intl`
+/// fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The month doesn't exist in the year, in the calendar it was given for, or is too close to
+    /// the ends of the range of dates for its six weeks to be laid out.
+    InvalidMonth { year: i32, month: u32 },
+    /// The first year of a range is after the last.
+    InvertedYearRange { from: i32, to: i32 },
+    /// The minimum date is after the maximum date.
+    InvertedDateRange { min: NaiveDate, max: NaiveDate },
+    /// The locale isn't a well-formed BCP 47 language tag.
+    UnsupportedLocale(String),
+    /// The JavaScript `Intl` API threw an error, with its message.
+    Intl(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidMonth { year, month } => {
+                write!(f, "month {} doesn't exist in year {}", month, year)
+            }
+            Error::InvertedYearRange { from, to } => {
+                write!(f, "the year range {} to {} is inverted", from, to)
+            }
+            Error::InvertedDateRange { min, max } => write!(
+                f,
+                "the minimum date {} is after the maximum date {}",
+                min, max
+            ),
+            Error::UnsupportedLocale(locale) => write!(f, "unsupported locale \"{}\"", locale),
+            Error::Intl(message) => write!(f, "Intl error: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Error {
+    pub(crate) fn from_js(value: wasm_bindgen::JsValue) -> Self {
+        use wasm_bindgen::JsCast;
+
+        let message = match value.dyn_ref::<js_sys::Error>() {
+            Some(error) => error.message().into(),
+            None => value.as_string().unwrap_or_default(),
+        };
+
+        Error::Intl(message)
+    }
+
+    pub(crate) fn check_month(
+        calendar: crate::calendar::Calendar,
+        year: i32,
+        month: u32,
+    ) -> Result<NaiveDate, Error> {
+        // The six weeks of a month start up to six days before it
+        calendar
+            .first_of_month(year, month)
+            .filter(|first_of_month| {
+                first_of_month
+                    .checked_sub_signed(Duration::days(6))
+                    .is_some()
+                    && first_of_month
+                        .checked_add_signed(Duration::days(41))
+                        .is_some()
+            })
+            .ok_or(Error::InvalidMonth { year, month })
+    }
+
+    pub(crate) fn check_date_range(min: NaiveDate, max: NaiveDate) -> Result<(), Error> {
+        if min > max {
+            Err(Error::InvertedDateRange { min, max })
+        } else {
+            Ok(())
+        }
+    }
+}
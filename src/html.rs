@@ -0,0 +1,94 @@
+//! Static prerendering of views to HTML, e.g. on the server, so the calendar is shown before the
+//! wasm app has loaded.
+//!
+//! This isn't hydration. Seed can't attach to existing markup, so when the app starts it takes over
+//! the contents of its mount point and replaces the prerendered markup with the live view. Render
+//! with the same configuration as the app, including `with_today`, so that nothing shifts then.
+//!
+//! Without the JavaScript `Intl` API, which includes all native targets, dates are formatted with
+//! the bundled CLDR data. Enable the `cldr` feature to render in other locales than English.
+
+use seed::prelude::*;
+use std::fmt::Write;
+
+// https://developer.mozilla.org/en-US/docs/Glossary/empty_element
+const EMPTY_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Renders a node, such as the result of a view's `into_node`, to an HTML string. Text and
+/// attribute values are escaped. Event handlers are left out, as they're attached by the app.
+pub fn render<Ms>(node: &Node<Ms>) -> String {
+    let mut output = String::new();
+    helpers::write_node(&mut output, node);
+    output
+}
+
+// Helpers
+
+mod helpers {
+    use super::*;
+
+    pub fn write_node<Ms>(output: &mut String, node: &Node<Ms>) {
+        match node {
+            Node::Element(el) => write_element(output, el),
+            Node::Text(text) => write_escaped(output, &text.text, false),
+            Node::Empty | Node::NoChange => (),
+        }
+    }
+
+    fn write_element<Ms>(output: &mut String, el: &El<Ms>) {
+        let tag = el.tag.as_str();
+
+        output.push('<');
+        output.push_str(tag);
+
+        for (name, value) in &el.attrs.vals {
+            match value {
+                AtValue::Ignored => (),
+                AtValue::None => write!(output, " {}", name.as_str()).unwrap(),
+                AtValue::Some(value) => write_attribute(output, name.as_str(), value),
+            }
+        }
+
+        let style = el.style.to_string();
+        if !style.is_empty() {
+            write_attribute(output, "style", &style);
+        }
+
+        if let Some(namespace) = &el.namespace {
+            write_attribute(output, "xmlns", namespace.as_str());
+        }
+
+        output.push('>');
+
+        if EMPTY_ELEMENTS.contains(&tag.to_ascii_lowercase().as_str()) {
+            return;
+        }
+
+        for child in &el.children {
+            write_node(output, child);
+        }
+
+        write!(output, "</{}>", tag).unwrap();
+    }
+
+    fn write_attribute(output: &mut String, name: &str, value: &str) {
+        write!(output, " {}=\"", name).unwrap();
+        write_escaped(output, value, true);
+        output.push('"');
+    }
+
+    fn write_escaped(output: &mut String, text: &str, in_attribute: bool) {
+        for c in text.chars() {
+            match c {
+                '&' => output.push_str("&amp;"),
+                '<' => output.push_str("&lt;"),
+                '>' => output.push_str("&gt;"),
+                '"' if in_attribute => output.push_str("&quot;"),
+                _ => output.push(c),
+            }
+        }
+    }
+}
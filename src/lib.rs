@@ -1,3 +1,16 @@
+mod error;
 mod util;
 
+pub mod calendar;
+pub mod component;
+pub mod core;
+pub mod html;
+pub mod locale;
 pub mod view;
+
+pub use error::Error;
+pub use locale::Locale;
+pub use util::date::today;
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub use util::formatters::set_formatter_caching;
@@ -0,0 +1,163 @@
+//! Locales, as BCP 47 language tags, that the views format and lay out dates for.
+//!
+//! Tags are canonicalized with `Intl` where it's available, and by their case conventions
+//! otherwise, so that e.g. `"EN-us"` and `"en-US"` are the same locale. Which locales are
+//! supported depends on the environment: `Intl` supports most, while the bundled CLDR data only
+//! covers English, and a selection of other languages with the `cldr` feature.
+
+use std::fmt;
+use std::str::FromStr;
+
+use wasm_bindgen::prelude::*;
+
+use crate::util::{format, intl};
+use crate::Error;
+
+/// A canonicalized BCP 47 language tag, e.g. `en-US` or `th-TH-u-nu-thai`.
+///
+/// Converting from a string with `From` never fails, but falls back to the default, `en-US`, if
+/// the tag isn't well-formed. Use `Locale::new` or `parse` to handle malformed tags instead.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Locale(String);
+
+impl Locale {
+    /// Canonicalizes the tag, or fails if it isn't a well-formed language tag.
+    pub fn new(tag: &str) -> Result<Locale, Error> {
+        let canonical = if intl::is_available() {
+            intl::get_canonical_locales(&JsValue::from(tag))
+                .ok()
+                .and_then(|locales| locales.get(0).as_string())
+        } else {
+            helpers::canonicalize(tag)
+        };
+
+        canonical
+            .map(Locale)
+            .ok_or_else(|| Error::UnsupportedLocale(String::from(tag)))
+    }
+
+    /// The first of the preferred locales that is supported, e.g. given `navigator.languages`.
+    /// A preference matches a supported locale that is less specific, so `de-CH` is picked if `de`
+    /// is supported. Malformed tags are skipped.
+    pub fn negotiate<I>(preferences: I) -> Option<Locale>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        preferences
+            .into_iter()
+            .filter_map(|tag| Locale::new(tag.as_ref()).ok())
+            .find(|locale| format::supports_locale(&locale.0))
+    }
+
+    /// The locale that dates are actually formatted in, as reported by the `resolvedOptions` of
+    /// `Intl.DateTimeFormat`, or the bundled language otherwise. Unsupported locales resolve to a
+    /// fallback, e.g. `en` where only English is bundled.
+    pub fn resolved(&self) -> Locale {
+        Locale(format::resolved_locale(&self.0))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The primary language subtag, e.g. `th` for `th-TH-u-nu-thai`.
+    pub fn language(&self) -> &str {
+        self.0.split('-').next().unwrap_or_default()
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale(String::from("en-US"))
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for Locale {
+    type Err = Error;
+
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        Locale::new(tag)
+    }
+}
+
+impl AsRef<str> for Locale {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for Locale {
+    fn from(tag: &str) -> Self {
+        Locale::new(tag).unwrap_or_default()
+    }
+}
+
+impl From<String> for Locale {
+    fn from(tag: String) -> Self {
+        Locale::from(tag.as_str())
+    }
+}
+
+impl From<&Locale> for Locale {
+    fn from(locale: &Locale) -> Self {
+        locale.clone()
+    }
+}
+
+impl From<Locale> for String {
+    fn from(locale: Locale) -> Self {
+        locale.0
+    }
+}
+
+// Helpers
+
+mod helpers {
+    // Checks the shape of the subtags, and applies the case conventions of BCP 47: lowercase
+    // languages, titlecase scripts and uppercase regions, with extensions all lowercase
+    pub fn canonicalize(tag: &str) -> Option<String> {
+        let mut subtags = tag.split('-');
+        let language = subtags.next().unwrap_or_default();
+
+        if !matches!(language.len(), 2..=3 | 5..=8)
+            || !language.chars().all(|c| c.is_ascii_alphabetic())
+        {
+            return None;
+        }
+
+        let mut canonical = language.to_ascii_lowercase();
+        let mut in_extension = false;
+
+        for subtag in subtags {
+            if !(1..=8).contains(&subtag.len())
+                || !subtag.chars().all(|c| c.is_ascii_alphanumeric())
+            {
+                return None;
+            }
+
+            in_extension |= subtag.len() == 1;
+            let is_alphabetic = subtag.chars().all(|c| c.is_ascii_alphabetic());
+
+            canonical.push('-');
+            match subtag.len() {
+                4 if is_alphabetic && !in_extension => {
+                    canonical.push_str(&subtag[..1].to_ascii_uppercase());
+                    canonical.push_str(&subtag[1..].to_ascii_lowercase());
+                }
+                2 if is_alphabetic && !in_extension => {
+                    canonical.push_str(&subtag.to_ascii_uppercase())
+                }
+                _ => canonical.push_str(&subtag.to_ascii_lowercase()),
+            }
+        }
+
+        Some(canonical)
+    }
+}
@@ -1 +1,7 @@
+pub mod date;
+pub mod direction;
+pub mod format;
+pub mod formatters;
 pub mod intl;
+pub mod locale;
+pub mod week_info;
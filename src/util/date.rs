@@ -0,0 +1,13 @@
+use chrono::NaiveDate;
+
+/// The current date in the browser's local time zone. Only available in the browser; when
+/// rendering on the server, pass the date to `with_today` instead.
+pub fn today() -> NaiveDate {
+    let now = js_sys::Date::new_0();
+
+    NaiveDate::from_ymd(
+        now.get_full_year() as i32,
+        now.get_month() + 1,
+        now.get_date(),
+    )
+}
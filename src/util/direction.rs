@@ -0,0 +1,133 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Ltr,
+    Rtl,
+}
+
+impl Direction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Direction::Ltr => "ltr",
+            Direction::Rtl => "rtl",
+        }
+    }
+
+    // Arrows pointing towards the start and end of the line, respectively
+
+    pub fn previous_arrow(self) -> &'static str {
+        match self {
+            Direction::Ltr => "‹",
+            Direction::Rtl => "›",
+        }
+    }
+
+    pub fn next_arrow(self) -> &'static str {
+        match self {
+            Direction::Ltr => "›",
+            Direction::Rtl => "‹",
+        }
+    }
+
+    // Arrow keys move visually, so left and right swap meaning in right-to-left layouts
+    pub fn logical_key(self, key: String) -> String {
+        match (self, key.as_str()) {
+            (Direction::Rtl, "ArrowLeft") => String::from("ArrowRight"),
+            (Direction::Rtl, "ArrowRight") => String::from("ArrowLeft"),
+            _ => key,
+        }
+    }
+}
+
+// Bounds the cache, which only grows past a handful of locales if they're generated dynamically
+const MAX_ENTRIES: usize = 256;
+
+thread_local! {
+    static DIRECTIONS: RefCell<HashMap<String, Direction>> = RefCell::new(HashMap::new());
+}
+
+/// The text direction of the given locale, as reported by `Intl.Locale` where supported, or
+/// from the script or language of the tag otherwise. Cached per locale, since views render with
+/// it every time.
+pub fn for_locale(locale: &str) -> Direction {
+    DIRECTIONS.with(|cache| {
+        if let Some(&direction) = cache.borrow().get(locale) {
+            return direction;
+        }
+
+        let direction = from_intl(locale).unwrap_or_else(|| from_tag(locale));
+
+        let mut cache = cache.borrow_mut();
+        if cache.len() >= MAX_ENTRIES {
+            cache.clear();
+        }
+        cache.insert(String::from(locale), direction);
+
+        direction
+    })
+}
+
+fn from_intl(locale: &str) -> Option<Direction> {
+    use crate::util::intl;
+    use js_sys::*;
+    use wasm_bindgen::{prelude::*, JsCast};
+
+    if !intl::is_available() {
+        return None;
+    }
+
+    let locale = intl::Locale::new(locale).ok()?;
+    let text_info = match locale.get_text_info() {
+        Ok(text_info) => text_info,
+        Err(_) => locale.text_info().dyn_into::<Object>().ok()?,
+    };
+
+    match Reflect::get(&text_info, &JsValue::from("direction"))
+        .ok()?
+        .as_string()?
+        .as_str()
+    {
+        "rtl" => Some(Direction::Rtl),
+        "ltr" => Some(Direction::Ltr),
+        _ => None,
+    }
+}
+
+fn from_tag(locale: &str) -> Direction {
+    let subtags: Vec<String> = locale
+        .split(['-', '_'])
+        .take_while(|subtag| subtag.len() > 1)
+        .map(|subtag| subtag.to_ascii_lowercase())
+        .collect();
+
+    let script = subtags.iter().skip(1).find(|subtag| subtag.len() == 4);
+
+    let is_rtl = match script.map(String::as_str) {
+        Some("arab") | Some("hebr") | Some("thaa") | Some("syrc") | Some("nkoo") | Some("adlm")
+        | Some("rohg") => true,
+        Some(_) => false,
+        None => matches!(
+            subtags.first().map(String::as_str),
+            Some("ar")
+                | Some("he")
+                | Some("iw")
+                | Some("fa")
+                | Some("ur")
+                | Some("ps")
+                | Some("sd")
+                | Some("ug")
+                | Some("yi")
+                | Some("dv")
+                | Some("ckb")
+                | Some("syr")
+        ),
+    };
+
+    if is_rtl {
+        Direction::Rtl
+    } else {
+        Direction::Ltr
+    }
+}
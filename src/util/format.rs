@@ -0,0 +1,135 @@
+//! Locale-aware formatting of numbers and dates.
+//!
+//! Formatting goes through the JavaScript `Intl` API where it's available, and falls back to
+//! bundled CLDR data otherwise, e.g. when rendering on the server or in native tests, or when
+//! `Intl` rejects the locale. `Intl` can be left out entirely by disabling the `intl` feature.
+//! Only English is bundled by default; the `cldr` feature adds a selection of other locales.
+
+use chrono::{NaiveDate, Weekday};
+
+use crate::calendar::Calendar;
+use crate::Error;
+
+mod cldr;
+mod intl;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Width {
+    Narrow,
+    Short,
+    Long,
+}
+
+/// Formats a number according to the locale, using its native digits where the locale, or a
+/// `-u-nu-` extension, calls for them. Digits are never grouped, so years render as e.g. "2021".
+pub fn number(value: i64, locale: &str) -> String {
+    with_fallback(
+        || intl::number(value, locale),
+        || cldr::number(value, locale),
+    )
+}
+
+/// Formats a year of the given calendar according to the locale, including the era where the
+/// calendar calls for one, e.g. "Reiwa 3" in the Japanese calendar.
+pub fn year(year: i32, calendar: Calendar, locale: &str) -> String {
+    let first_day = match (calendar, calendar.to_date(year, 1, 1)) {
+        (Calendar::Gregorian, _) | (_, None) => return number(year.into(), locale),
+        (_, Some(date)) => date,
+    };
+
+    with_fallback(
+        || intl::year(first_day, calendar.identifier(), locale),
+        || cldr::year(first_day, calendar.identifier(), locale),
+    )
+}
+
+pub fn weekday(weekday: Weekday, width: Width, locale: &str) -> String {
+    with_fallback(
+        || intl::weekday(weekday, width, locale),
+        || cldr::weekday(weekday, width, locale),
+    )
+}
+
+/// The name of the month the date falls in. Standalone names are used on their own, as in a list
+/// of months, while others are used as part of a date.
+pub fn month(
+    date: NaiveDate,
+    width: Width,
+    standalone: bool,
+    calendar: &str,
+    locale: &str,
+) -> String {
+    with_fallback(
+        || intl::month(date, width, standalone, calendar, locale),
+        || cldr::month(date, width, standalone, calendar, locale),
+    )
+}
+
+pub fn month_and_year(date: NaiveDate, calendar: &str, locale: &str) -> String {
+    with_fallback(
+        || intl::month_and_year(date, calendar, locale),
+        || cldr::month_and_year(date, calendar, locale),
+    )
+}
+
+/// The months spanned by the given dates, e.g. "Ramadan – Shawwal 1442 AH".
+pub fn month_range(start: NaiveDate, end: NaiveDate, calendar: &str, locale: &str) -> String {
+    with_fallback(
+        || intl::month_range(start, end, calendar, locale),
+        || cldr::month_range(start, end, calendar, locale),
+    )
+}
+
+/// The full date, including the weekday, e.g. "Monday, April 12, 2021".
+pub fn date(date: NaiveDate, calendar: &str, locale: &str) -> String {
+    with_fallback(
+        || intl::date(date, calendar, locale),
+        || cldr::date(date, calendar, locale),
+    )
+}
+
+/// The day of the month, along with the month on its first day, e.g. "Ramadan 1".
+pub fn day_annotation(date: NaiveDate, calendar: &str, locale: &str) -> String {
+    with_fallback(
+        || intl::day_annotation(date, calendar, locale),
+        || cldr::day_annotation(date, calendar, locale),
+    )
+}
+
+/// Whether dates can be formatted in the calendar with the given BCP 47 identifier. The bundled
+/// data only covers the calendars of `Calendar`.
+pub fn supports_calendar(calendar: &str) -> bool {
+    use_intl() || Calendar::from_identifier(calendar).is_some()
+}
+
+/// Whether the locale, or a less specific one, e.g. `de` for `de-CH`, has data of its own, rather
+/// than falling back to English.
+pub fn supports_locale(locale: &str) -> bool {
+    (use_intl() && intl::supports_locale(locale)) || cldr::supports_locale(locale)
+}
+
+/// The locale that dates are formatted in, given the requested one.
+pub fn resolved_locale(locale: &str) -> String {
+    with_fallback(
+        || intl::resolved_locale(locale),
+        || cldr::resolved_locale(locale),
+    )
+}
+
+fn use_intl() -> bool {
+    crate::util::intl::is_available()
+}
+
+// Falls back to the bundled data where `Intl` is unavailable, or fails, e.g. on an invalid locale
+fn with_fallback(
+    intl: impl FnOnce() -> Result<String, Error>,
+    cldr: impl FnOnce() -> String,
+) -> String {
+    if use_intl() {
+        if let Ok(formatted) = intl() {
+            return formatted;
+        }
+    }
+
+    cldr()
+}
@@ -0,0 +1,266 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use super::Width;
+use crate::calendar::{Calendar, CalendarDate};
+
+mod data;
+
+use data::LocaleData;
+
+pub fn number(value: i64, locale: &str) -> String {
+    let zero = zero_digit(&numbering_system(locale));
+    let digits = value
+        .unsigned_abs()
+        .to_string()
+        .chars()
+        .map(|digit| {
+            let offset = digit.to_digit(10).unwrap();
+            std::char::from_u32(zero as u32 + offset).unwrap_or(digit)
+        })
+        .collect::<String>();
+
+    if value < 0 {
+        format!("-{}", digits)
+    } else {
+        digits
+    }
+}
+
+pub fn year(date: NaiveDate, calendar: &str, locale: &str) -> String {
+    let data = data::for_locale(&language(locale));
+
+    data.year.replace(
+        "{year}",
+        &year_with_era(date, calendar_for(calendar), data, locale),
+    )
+}
+
+pub fn weekday(weekday: Weekday, width: Width, locale: &str) -> String {
+    let data = data::for_locale(&language(locale));
+    let weekdays = match width {
+        Width::Narrow => &data.weekdays_narrow,
+        Width::Short => &data.weekdays_short,
+        Width::Long => &data.weekdays,
+    };
+
+    String::from(weekdays[weekday.num_days_from_monday() as usize])
+}
+
+pub fn month(
+    date: NaiveDate,
+    width: Width,
+    standalone: bool,
+    calendar: &str,
+    locale: &str,
+) -> String {
+    let calendar = calendar_for(calendar);
+    let data = data::for_locale(&language(locale));
+
+    month_name(
+        calendar.from_date(date),
+        calendar,
+        width,
+        standalone,
+        data,
+        locale,
+    )
+}
+
+pub fn month_and_year(date: NaiveDate, calendar: &str, locale: &str) -> String {
+    let calendar = calendar_for(calendar);
+    let data = data::for_locale(&language(locale));
+    let calendar_date = calendar.from_date(date);
+
+    data.month_year
+        .replace(
+            "{standalone_month}",
+            &month_name(calendar_date, calendar, Width::Long, true, data, locale),
+        )
+        .replace(
+            "{month}",
+            &month_name(calendar_date, calendar, Width::Long, false, data, locale),
+        )
+        .replace("{year}", &year_with_era(date, calendar, data, locale))
+}
+
+pub fn month_range(start: NaiveDate, end: NaiveDate, calendar: &str, locale: &str) -> String {
+    let start = month_and_year(start, calendar, locale);
+    let end = month_and_year(end, calendar, locale);
+
+    if start == end {
+        start
+    } else {
+        format!("{} – {}", start, end)
+    }
+}
+
+pub fn date(date: NaiveDate, calendar: &str, locale: &str) -> String {
+    let calendar = calendar_for(calendar);
+    let data = data::for_locale(&language(locale));
+    let calendar_date = calendar.from_date(date);
+
+    data.full_date
+        .replace(
+            "{weekday}",
+            data.weekdays[date.weekday().num_days_from_monday() as usize],
+        )
+        .replace(
+            "{month}",
+            &month_name(calendar_date, calendar, Width::Long, false, data, locale),
+        )
+        .replace("{day}", &number(calendar_date.day.into(), locale))
+        .replace("{year}", &year_with_era(date, calendar, data, locale))
+}
+
+pub fn day_annotation(date: NaiveDate, calendar: &str, locale: &str) -> String {
+    let calendar = calendar_for(calendar);
+    let data = data::for_locale(&language(locale));
+    let calendar_date = calendar.from_date(date);
+    let day = number(calendar_date.day.into(), locale);
+
+    if calendar_date.day == 1 {
+        data.day_month
+            .replace(
+                "{month}",
+                &month_name(calendar_date, calendar, Width::Short, false, data, locale),
+            )
+            .replace("{day}", &day)
+    } else {
+        day
+    }
+}
+
+pub fn supports_locale(locale: &str) -> bool {
+    data::is_bundled(&language(locale))
+}
+
+pub fn resolved_locale(locale: &str) -> String {
+    let language = language(locale);
+
+    if data::is_bundled(&language) {
+        language
+    } else {
+        String::from("en")
+    }
+}
+
+// Helpers
+
+// Calendars not covered by the bundled data are formatted as Gregorian
+fn calendar_for(identifier: &str) -> Calendar {
+    Calendar::from_identifier(identifier).unwrap_or(Calendar::Gregorian)
+}
+
+fn month_name(
+    date: CalendarDate,
+    calendar: Calendar,
+    width: Width,
+    standalone: bool,
+    data: &LocaleData,
+    locale: &str,
+) -> String {
+    let index = date.month as usize - 1;
+
+    let months = match calendar {
+        Calendar::Gregorian | Calendar::Buddhist | Calendar::Japanese => {
+            return String::from(match (width, standalone) {
+                (Width::Narrow, _) => data.months_narrow[index],
+                (Width::Short, _) => data.months_short[index],
+                (Width::Long, true) => data.standalone_months[index],
+                (Width::Long, false) => data.months[index],
+            })
+        }
+        // Months of other calendars are numbered in their narrow form
+        _ if width == Width::Narrow => return number(date.month.into(), locale),
+        Calendar::Hebrew => {
+            return String::from(data.hebrew_months[data::hebrew_month_index(date)]);
+        }
+        Calendar::Persian => &data.persian_months,
+        Calendar::IslamicCivil | Calendar::Islamic | Calendar::IslamicUmalqura => {
+            &data.islamic_months
+        }
+    };
+
+    String::from(months[index])
+}
+
+fn year_with_era(date: NaiveDate, calendar: Calendar, data: &LocaleData, locale: &str) -> String {
+    let year = calendar.from_date(date).year;
+
+    let era = match calendar {
+        Calendar::Gregorian => None,
+        Calendar::Japanese => {
+            let (era, year) = data::japanese_era(date);
+
+            return data
+                .japanese_year
+                .replace("{era}", data.japanese_eras[era])
+                .replace("{year}", &number(year.into(), locale));
+        }
+        Calendar::Buddhist => data.eras.buddhist,
+        Calendar::Hebrew => data.eras.hebrew,
+        Calendar::Persian => data.eras.persian,
+        Calendar::IslamicCivil | Calendar::Islamic | Calendar::IslamicUmalqura => data.eras.islamic,
+    };
+
+    match era {
+        Some(era) => format!("{} {}", number(year.into(), locale), era),
+        None => number(year.into(), locale),
+    }
+}
+
+fn subtags(locale: &str) -> Vec<String> {
+    locale
+        .split(['-', '_'])
+        .map(|subtag| subtag.to_ascii_lowercase())
+        .collect()
+}
+
+fn language(locale: &str) -> String {
+    subtags(locale).into_iter().next().unwrap_or_default()
+}
+
+// The numbering system set with the `-u-nu-` extension, or the default of the locale
+fn numbering_system(locale: &str) -> String {
+    let subtags = subtags(locale);
+
+    let keyword = subtags
+        .iter()
+        .position(|subtag| subtag == "u")
+        .and_then(|extension| {
+            subtags[extension..]
+                .iter()
+                .position(|subtag| subtag == "nu")
+                .and_then(|keyword| subtags.get(extension + keyword + 1))
+        });
+
+    if let Some(system) = keyword {
+        return system.clone();
+    }
+
+    let region = subtags
+        .iter()
+        .skip(1)
+        .take_while(|subtag| subtag.len() > 1)
+        .find(|subtag| subtag.len() == 2)
+        .map(String::as_str);
+
+    String::from(data::default_numbering_system(&subtags[0], region))
+}
+
+// The digit zero of decimal numbering systems, whose digits are all consecutive code points
+fn zero_digit(system: &str) -> char {
+    match system {
+        "arab" => '\u{0660}',
+        "arabext" => '\u{06F0}',
+        "beng" => '\u{09E6}',
+        "deva" => '\u{0966}',
+        "fullwide" => '\u{FF10}',
+        "khmr" => '\u{17E0}',
+        "laoo" => '\u{0ED0}',
+        "mymr" => '\u{1040}',
+        "tamldec" => '\u{0BE6}',
+        "thai" => '\u{0E50}',
+        _ => '0',
+    }
+}
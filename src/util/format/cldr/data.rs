@@ -0,0 +1,1029 @@
+// A subset of the CLDR locale data. English is always included, other locales only with the
+// `cldr` feature. Locales not included fall back to English.
+
+use chrono::NaiveDate;
+
+use crate::calendar::{Calendar, CalendarDate};
+
+pub struct LocaleData {
+    pub months: [&'static str; 12],
+    pub standalone_months: [&'static str; 12],
+    pub months_short: [&'static str; 12],
+    pub months_narrow: [&'static str; 12],
+    /// Monday first
+    pub weekdays: [&'static str; 7],
+    pub weekdays_short: [&'static str; 7],
+    pub weekdays_narrow: [&'static str; 7],
+    /// Tishri to Elul, with Adar I, Adar and Adar II all included
+    pub hebrew_months: [&'static str; 14],
+    pub islamic_months: [&'static str; 12],
+    pub persian_months: [&'static str; 12],
+    pub eras: Eras,
+    /// Meiji to Reiwa
+    pub japanese_eras: [&'static str; 5],
+    // Patterns
+    pub month_year: &'static str,
+    pub full_date: &'static str,
+    pub day_month: &'static str,
+    pub year: &'static str,
+    pub japanese_year: &'static str,
+}
+
+pub struct Eras {
+    pub buddhist: Option<&'static str>,
+    pub hebrew: Option<&'static str>,
+    pub islamic: Option<&'static str>,
+    pub persian: Option<&'static str>,
+}
+
+pub fn for_locale(language: &str) -> &'static LocaleData {
+    bundled(language).unwrap_or(&EN)
+}
+
+/// Whether the language has data of its own, rather than falling back to English.
+pub fn is_bundled(language: &str) -> bool {
+    language == "en" || bundled(language).is_some()
+}
+
+fn bundled(language: &str) -> Option<&'static LocaleData> {
+    match language {
+        #[cfg(feature = "cldr")]
+        "ar" => Some(&locales::AR),
+        #[cfg(feature = "cldr")]
+        "da" => Some(&locales::DA),
+        #[cfg(feature = "cldr")]
+        "de" => Some(&locales::DE),
+        #[cfg(feature = "cldr")]
+        "es" => Some(&locales::ES),
+        #[cfg(feature = "cldr")]
+        "fa" => Some(&locales::FA),
+        #[cfg(feature = "cldr")]
+        "fi" => Some(&locales::FI),
+        #[cfg(feature = "cldr")]
+        "fr" => Some(&locales::FR),
+        #[cfg(feature = "cldr")]
+        "he" | "iw" => Some(&locales::HE),
+        #[cfg(feature = "cldr")]
+        "it" => Some(&locales::IT),
+        #[cfg(feature = "cldr")]
+        "ja" => Some(&locales::JA),
+        #[cfg(feature = "cldr")]
+        "nb" | "nn" | "no" => Some(&locales::NB),
+        #[cfg(feature = "cldr")]
+        "nl" => Some(&locales::NL),
+        #[cfg(feature = "cldr")]
+        "pl" => Some(&locales::PL),
+        #[cfg(feature = "cldr")]
+        "pt" => Some(&locales::PT),
+        #[cfg(feature = "cldr")]
+        "ru" => Some(&locales::RU),
+        #[cfg(feature = "cldr")]
+        "sv" => Some(&locales::SV),
+        #[cfg(feature = "cldr")]
+        "th" => Some(&locales::TH),
+        #[cfg(feature = "cldr")]
+        "zh" => Some(&locales::ZH),
+        _ => None,
+    }
+}
+
+pub fn default_numbering_system(language: &str, region: Option<&str>) -> &'static str {
+    match (language, region) {
+        ("ar", Some("dz"))
+        | ("ar", Some("eh"))
+        | ("ar", Some("ly"))
+        | ("ar", Some("ma"))
+        | ("ar", Some("tn")) => "latn",
+        ("ar", _) => "arab",
+        ("fa", _) => "arabext",
+        _ => "latn",
+    }
+}
+
+pub fn hebrew_month_index(date: CalendarDate) -> usize {
+    let is_leap_year = Calendar::Hebrew.months_in_year(date.year) == 13;
+
+    match (is_leap_year, date.month) {
+        (_, 1..=5) => date.month as usize - 1,
+        (false, 6) => 6,
+        (true, 6) => 5,
+        (true, 7) => 7,
+        (false, month) => month as usize + 1,
+        (true, month) => month as usize,
+    }
+}
+
+/// The index of the era, along with the year within it.
+pub fn japanese_era(date: NaiveDate) -> (usize, i32) {
+    const ERAS: [(i32, u32, u32); 5] = [
+        (1868, 9, 8),
+        (1912, 7, 30),
+        (1926, 12, 25),
+        (1989, 1, 8),
+        (2019, 5, 1),
+    ];
+
+    let era = ERAS
+        .iter()
+        .rposition(|&(year, month, day)| date >= NaiveDate::from_ymd(year, month, day))
+        .unwrap_or(0);
+
+    (era, chrono::Datelike::year(&date) - ERAS[era].0 + 1)
+}
+
+const EN_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+#[cfg(feature = "cldr")]
+const NUMERIC_MONTHS: [&str; 12] = [
+    "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12",
+];
+
+const LATIN_NARROW_MONTHS: [&str; 12] =
+    ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"];
+
+pub const EN: LocaleData = LocaleData {
+    months: EN_MONTHS,
+    standalone_months: EN_MONTHS,
+    months_short: [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ],
+    months_narrow: LATIN_NARROW_MONTHS,
+    weekdays: [
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+        "Sunday",
+    ],
+    weekdays_short: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+    weekdays_narrow: ["M", "T", "W", "T", "F", "S", "S"],
+    hebrew_months: [
+        "Tishri", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar", "Adar II", "Nisan",
+        "Iyar", "Sivan", "Tamuz", "Av", "Elul",
+    ],
+    islamic_months: [
+        "Muharram",
+        "Safar",
+        "Rabiʻ I",
+        "Rabiʻ II",
+        "Jumada I",
+        "Jumada II",
+        "Rajab",
+        "Shaʻban",
+        "Ramadan",
+        "Shawwal",
+        "Dhuʻl-Qiʻdah",
+        "Dhuʻl-Hijjah",
+    ],
+    persian_months: [
+        "Farvardin",
+        "Ordibehesht",
+        "Khordad",
+        "Tir",
+        "Mordad",
+        "Shahrivar",
+        "Mehr",
+        "Aban",
+        "Azar",
+        "Dey",
+        "Bahman",
+        "Esfand",
+    ],
+    eras: Eras {
+        buddhist: Some("BE"),
+        hebrew: Some("AM"),
+        islamic: Some("AH"),
+        persian: Some("AP"),
+    },
+    japanese_eras: ["Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"],
+    month_year: "{month} {year}",
+    full_date: "{weekday}, {month} {day}, {year}",
+    day_month: "{month} {day}",
+    year: "{year}",
+    japanese_year: "{year} {era}",
+};
+
+// Eras are only named in English, and left out elsewhere
+#[cfg(feature = "cldr")]
+const NO_ERAS: Eras = Eras {
+    buddhist: None,
+    hebrew: None,
+    islamic: None,
+    persian: None,
+};
+
+#[cfg(feature = "cldr")]
+mod locales {
+    use super::{Eras, LocaleData, EN, LATIN_NARROW_MONTHS, NO_ERAS, NUMERIC_MONTHS};
+
+    pub const AR: LocaleData = LocaleData {
+        months: AR_MONTHS,
+        standalone_months: AR_MONTHS,
+        months_short: AR_MONTHS,
+        months_narrow: ["ي", "ف", "م", "أ", "و", "ن", "ل", "غ", "س", "ك", "ب", "د"],
+        weekdays: AR_WEEKDAYS,
+        weekdays_short: AR_WEEKDAYS,
+        weekdays_narrow: ["ن", "ث", "ر", "خ", "ج", "س", "ح"],
+        islamic_months: [
+            "محرم",
+            "صفر",
+            "ربيع الأول",
+            "ربيع الآخر",
+            "جمادى الأولى",
+            "جمادى الآخرة",
+            "رجب",
+            "شعبان",
+            "رمضان",
+            "شوال",
+            "ذو القعدة",
+            "ذو الحجة",
+        ],
+        eras: NO_ERAS,
+        full_date: "{weekday}، {day} {month} {year}",
+        day_month: "{day} {month}",
+        ..EN
+    };
+
+    const AR_MONTHS: [&str; 12] = [
+        "يناير",
+        "فبراير",
+        "مارس",
+        "أبريل",
+        "مايو",
+        "يونيو",
+        "يوليو",
+        "أغسطس",
+        "سبتمبر",
+        "أكتوبر",
+        "نوفمبر",
+        "ديسمبر",
+    ];
+
+    const AR_WEEKDAYS: [&str; 7] = [
+        "الاثنين",
+        "الثلاثاء",
+        "الأربعاء",
+        "الخميس",
+        "الجمعة",
+        "السبت",
+        "الأحد",
+    ];
+
+    pub const DA: LocaleData = LocaleData {
+        months: DA_MONTHS,
+        standalone_months: DA_MONTHS,
+        months_short: [
+            "jan.", "feb.", "mar.", "apr.", "maj", "jun.", "jul.", "aug.", "sep.", "okt.", "nov.",
+            "dec.",
+        ],
+        months_narrow: LATIN_NARROW_MONTHS,
+        weekdays: [
+            "mandag", "tirsdag", "onsdag", "torsdag", "fredag", "lørdag", "søndag",
+        ],
+        weekdays_short: ["man.", "tirs.", "ons.", "tors.", "fre.", "lør.", "søn."],
+        weekdays_narrow: ["M", "T", "O", "T", "F", "L", "S"],
+        eras: NO_ERAS,
+        full_date: "{weekday} den {day}. {month} {year}",
+        day_month: "{day}. {month}",
+        ..EN
+    };
+
+    const DA_MONTHS: [&str; 12] = [
+        "januar",
+        "februar",
+        "marts",
+        "april",
+        "maj",
+        "juni",
+        "juli",
+        "august",
+        "september",
+        "oktober",
+        "november",
+        "december",
+    ];
+
+    pub const DE: LocaleData = LocaleData {
+        months: DE_MONTHS,
+        standalone_months: DE_MONTHS,
+        months_short: [
+            "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+            "Dez.",
+        ],
+        months_narrow: LATIN_NARROW_MONTHS,
+        weekdays: [
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+            "Sonntag",
+        ],
+        weekdays_short: ["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."],
+        weekdays_narrow: ["M", "D", "M", "D", "F", "S", "S"],
+        eras: NO_ERAS,
+        full_date: "{weekday}, {day}. {month} {year}",
+        day_month: "{day}. {month}",
+        ..EN
+    };
+
+    const DE_MONTHS: [&str; 12] = [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ];
+
+    pub const ES: LocaleData = LocaleData {
+        months: ES_MONTHS,
+        standalone_months: ES_MONTHS,
+        months_short: [
+            "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+        ],
+        months_narrow: ["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+        weekdays: [
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+            "domingo",
+        ],
+        weekdays_short: ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+        weekdays_narrow: ["L", "M", "X", "J", "V", "S", "D"],
+        eras: NO_ERAS,
+        month_year: "{month} de {year}",
+        full_date: "{weekday}, {day} de {month} de {year}",
+        day_month: "{day} {month}",
+        ..EN
+    };
+
+    const ES_MONTHS: [&str; 12] = [
+        "enero",
+        "febrero",
+        "marzo",
+        "abril",
+        "mayo",
+        "junio",
+        "julio",
+        "agosto",
+        "septiembre",
+        "octubre",
+        "noviembre",
+        "diciembre",
+    ];
+
+    pub const FA: LocaleData = LocaleData {
+        months: FA_MONTHS,
+        standalone_months: FA_MONTHS,
+        months_short: FA_MONTHS,
+        months_narrow: ["ژ", "ف", "م", "آ", "م", "ژ", "ژ", "ا", "س", "ا", "ن", "د"],
+        weekdays: FA_WEEKDAYS,
+        weekdays_short: FA_WEEKDAYS,
+        weekdays_narrow: ["د", "س", "چ", "پ", "ج", "ش", "ی"],
+        persian_months: [
+            "فروردین",
+            "اردیبهشت",
+            "خرداد",
+            "تیر",
+            "مرداد",
+            "شهریور",
+            "مهر",
+            "آبان",
+            "آذر",
+            "دی",
+            "بهمن",
+            "اسفند",
+        ],
+        eras: NO_ERAS,
+        full_date: "{weekday} {day} {month} {year}",
+        day_month: "{day} {month}",
+        ..EN
+    };
+
+    const FA_MONTHS: [&str; 12] = [
+        "ژانویه",
+        "فوریه",
+        "مارس",
+        "آوریل",
+        "مه",
+        "ژوئن",
+        "ژوئیه",
+        "اوت",
+        "سپتامبر",
+        "اکتبر",
+        "نوامبر",
+        "دسامبر",
+    ];
+
+    const FA_WEEKDAYS: [&str; 7] = [
+        "دوشنبه",
+        "سه‌شنبه",
+        "چهارشنبه",
+        "پنجشنبه",
+        "جمعه",
+        "شنبه",
+        "یکشنبه",
+    ];
+
+    pub const FI: LocaleData = LocaleData {
+        months: [
+            "tammikuuta",
+            "helmikuuta",
+            "maaliskuuta",
+            "huhtikuuta",
+            "toukokuuta",
+            "kesäkuuta",
+            "heinäkuuta",
+            "elokuuta",
+            "syyskuuta",
+            "lokakuuta",
+            "marraskuuta",
+            "joulukuuta",
+        ],
+        standalone_months: [
+            "tammikuu",
+            "helmikuu",
+            "maaliskuu",
+            "huhtikuu",
+            "toukokuu",
+            "kesäkuu",
+            "heinäkuu",
+            "elokuu",
+            "syyskuu",
+            "lokakuu",
+            "marraskuu",
+            "joulukuu",
+        ],
+        months_short: [
+            "tammik.", "helmik.", "maalisk.", "huhtik.", "toukok.", "kesäk.", "heinäk.", "elok.",
+            "syysk.", "lokak.", "marrask.", "jouluk.",
+        ],
+        months_narrow: ["T", "H", "M", "H", "T", "K", "H", "E", "S", "L", "M", "J"],
+        weekdays: [
+            "maanantai",
+            "tiistai",
+            "keskiviikko",
+            "torstai",
+            "perjantai",
+            "lauantai",
+            "sunnuntai",
+        ],
+        weekdays_short: ["ma", "ti", "ke", "to", "pe", "la", "su"],
+        weekdays_narrow: ["M", "T", "K", "T", "P", "L", "S"],
+        eras: NO_ERAS,
+        month_year: "{standalone_month} {year}",
+        full_date: "{weekday} {day}. {month} {year}",
+        day_month: "{day}. {month}",
+        ..EN
+    };
+
+    pub const FR: LocaleData = LocaleData {
+        months: FR_MONTHS,
+        standalone_months: FR_MONTHS,
+        months_short: [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ],
+        months_narrow: LATIN_NARROW_MONTHS,
+        weekdays: [
+            "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+        ],
+        weekdays_short: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+        weekdays_narrow: ["L", "M", "M", "J", "V", "S", "D"],
+        eras: NO_ERAS,
+        full_date: "{weekday} {day} {month} {year}",
+        day_month: "{day} {month}",
+        ..EN
+    };
+
+    const FR_MONTHS: [&str; 12] = [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ];
+
+    pub const HE: LocaleData = LocaleData {
+        months: HE_MONTHS,
+        standalone_months: HE_MONTHS,
+        months_short: [
+            "ינו׳", "פבר׳", "מרץ", "אפר׳", "מאי", "יוני", "יולי", "אוג׳", "ספט׳", "אוק׳", "נוב׳",
+            "דצמ׳",
+        ],
+        months_narrow: NUMERIC_MONTHS,
+        weekdays: [
+            "יום שני",
+            "יום שלישי",
+            "יום רביעי",
+            "יום חמישי",
+            "יום שישי",
+            "יום שבת",
+            "יום ראשון",
+        ],
+        weekdays_short: [
+            "יום ב׳",
+            "יום ג׳",
+            "יום ד׳",
+            "יום ה׳",
+            "יום ו׳",
+            "שבת",
+            "יום א׳",
+        ],
+        weekdays_narrow: ["ב׳", "ג׳", "ד׳", "ה׳", "ו׳", "ש׳", "א׳"],
+        hebrew_months: [
+            "תשרי",
+            "חשוון",
+            "כסלו",
+            "טבת",
+            "שבט",
+            "אדר א׳",
+            "אדר",
+            "אדר ב׳",
+            "ניסן",
+            "אייר",
+            "סיוון",
+            "תמוז",
+            "אב",
+            "אלול",
+        ],
+        eras: NO_ERAS,
+        full_date: "{weekday}, {day} ב{month} {year}",
+        day_month: "{day} ב{month}",
+        ..EN
+    };
+
+    const HE_MONTHS: [&str; 12] = [
+        "ינואר",
+        "פברואר",
+        "מרץ",
+        "אפריל",
+        "מאי",
+        "יוני",
+        "יולי",
+        "אוגוסט",
+        "ספטמבר",
+        "אוקטובר",
+        "נובמבר",
+        "דצמבר",
+    ];
+
+    pub const IT: LocaleData = LocaleData {
+        months: IT_MONTHS,
+        standalone_months: IT_MONTHS,
+        months_short: [
+            "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+        ],
+        months_narrow: ["G", "F", "M", "A", "M", "G", "L", "A", "S", "O", "N", "D"],
+        weekdays: [
+            "lunedì",
+            "martedì",
+            "mercoledì",
+            "giovedì",
+            "venerdì",
+            "sabato",
+            "domenica",
+        ],
+        weekdays_short: ["lun", "mar", "mer", "gio", "ven", "sab", "dom"],
+        weekdays_narrow: ["L", "M", "M", "G", "V", "S", "D"],
+        eras: NO_ERAS,
+        full_date: "{weekday} {day} {month} {year}",
+        day_month: "{day} {month}",
+        ..EN
+    };
+
+    const IT_MONTHS: [&str; 12] = [
+        "gennaio",
+        "febbraio",
+        "marzo",
+        "aprile",
+        "maggio",
+        "giugno",
+        "luglio",
+        "agosto",
+        "settembre",
+        "ottobre",
+        "novembre",
+        "dicembre",
+    ];
+
+    pub const JA: LocaleData = LocaleData {
+        months: JA_MONTHS,
+        standalone_months: JA_MONTHS,
+        months_short: JA_MONTHS,
+        months_narrow: NUMERIC_MONTHS,
+        weekdays: [
+            "月曜日",
+            "火曜日",
+            "水曜日",
+            "木曜日",
+            "金曜日",
+            "土曜日",
+            "日曜日",
+        ],
+        weekdays_short: ["月", "火", "水", "木", "金", "土", "日"],
+        weekdays_narrow: ["月", "火", "水", "木", "金", "土", "日"],
+        eras: NO_ERAS,
+        japanese_eras: ["明治", "大正", "昭和", "平成", "令和"],
+        month_year: "{year}年{month}",
+        full_date: "{year}年{month}{day}日{weekday}",
+        day_month: "{month}{day}日",
+        year: "{year}年",
+        japanese_year: "{era}{year}",
+        ..EN
+    };
+
+    const JA_MONTHS: [&str; 12] = [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ];
+
+    pub const NB: LocaleData = LocaleData {
+        months: NB_MONTHS,
+        standalone_months: NB_MONTHS,
+        months_short: [
+            "jan.", "feb.", "mar.", "apr.", "mai", "jun.", "jul.", "aug.", "sep.", "okt.", "nov.",
+            "des.",
+        ],
+        months_narrow: LATIN_NARROW_MONTHS,
+        weekdays: [
+            "mandag", "tirsdag", "onsdag", "torsdag", "fredag", "lørdag", "søndag",
+        ],
+        weekdays_short: ["man.", "tir.", "ons.", "tor.", "fre.", "lør.", "søn."],
+        weekdays_narrow: ["M", "T", "O", "T", "F", "L", "S"],
+        eras: NO_ERAS,
+        full_date: "{weekday} {day}. {month} {year}",
+        day_month: "{day}. {month}",
+        ..EN
+    };
+
+    const NB_MONTHS: [&str; 12] = [
+        "januar",
+        "februar",
+        "mars",
+        "april",
+        "mai",
+        "juni",
+        "juli",
+        "august",
+        "september",
+        "oktober",
+        "november",
+        "desember",
+    ];
+
+    pub const NL: LocaleData = LocaleData {
+        months: NL_MONTHS,
+        standalone_months: NL_MONTHS,
+        months_short: [
+            "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec",
+        ],
+        months_narrow: LATIN_NARROW_MONTHS,
+        weekdays: [
+            "maandag",
+            "dinsdag",
+            "woensdag",
+            "donderdag",
+            "vrijdag",
+            "zaterdag",
+            "zondag",
+        ],
+        weekdays_short: ["ma", "di", "wo", "do", "vr", "za", "zo"],
+        weekdays_narrow: ["M", "D", "W", "D", "V", "Z", "Z"],
+        eras: NO_ERAS,
+        full_date: "{weekday} {day} {month} {year}",
+        day_month: "{day} {month}",
+        ..EN
+    };
+
+    const NL_MONTHS: [&str; 12] = [
+        "januari",
+        "februari",
+        "maart",
+        "april",
+        "mei",
+        "juni",
+        "juli",
+        "augustus",
+        "september",
+        "oktober",
+        "november",
+        "december",
+    ];
+
+    pub const PL: LocaleData = LocaleData {
+        months: [
+            "stycznia",
+            "lutego",
+            "marca",
+            "kwietnia",
+            "maja",
+            "czerwca",
+            "lipca",
+            "sierpnia",
+            "września",
+            "października",
+            "listopada",
+            "grudnia",
+        ],
+        standalone_months: [
+            "styczeń",
+            "luty",
+            "marzec",
+            "kwiecień",
+            "maj",
+            "czerwiec",
+            "lipiec",
+            "sierpień",
+            "wrzesień",
+            "październik",
+            "listopad",
+            "grudzień",
+        ],
+        months_short: [
+            "sty", "lut", "mar", "kwi", "maj", "cze", "lip", "sie", "wrz", "paź", "lis", "gru",
+        ],
+        months_narrow: ["S", "L", "M", "K", "M", "C", "L", "S", "W", "P", "L", "G"],
+        weekdays: [
+            "poniedziałek",
+            "wtorek",
+            "środa",
+            "czwartek",
+            "piątek",
+            "sobota",
+            "niedziela",
+        ],
+        weekdays_short: ["pon.", "wt.", "śr.", "czw.", "pt.", "sob.", "niedz."],
+        weekdays_narrow: ["P", "W", "Ś", "C", "P", "S", "N"],
+        eras: NO_ERAS,
+        month_year: "{standalone_month} {year}",
+        full_date: "{weekday}, {day} {month} {year}",
+        day_month: "{day} {month}",
+        ..EN
+    };
+
+    pub const PT: LocaleData = LocaleData {
+        months: PT_MONTHS,
+        standalone_months: PT_MONTHS,
+        months_short: [
+            "jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.",
+            "dez.",
+        ],
+        months_narrow: LATIN_NARROW_MONTHS,
+        weekdays: [
+            "segunda-feira",
+            "terça-feira",
+            "quarta-feira",
+            "quinta-feira",
+            "sexta-feira",
+            "sábado",
+            "domingo",
+        ],
+        weekdays_short: ["seg.", "ter.", "qua.", "qui.", "sex.", "sáb.", "dom."],
+        weekdays_narrow: ["S", "T", "Q", "Q", "S", "S", "D"],
+        eras: NO_ERAS,
+        month_year: "{month} de {year}",
+        full_date: "{weekday}, {day} de {month} de {year}",
+        day_month: "{day} de {month}",
+        ..EN
+    };
+
+    const PT_MONTHS: [&str; 12] = [
+        "janeiro",
+        "fevereiro",
+        "março",
+        "abril",
+        "maio",
+        "junho",
+        "julho",
+        "agosto",
+        "setembro",
+        "outubro",
+        "novembro",
+        "dezembro",
+    ];
+
+    pub const RU: LocaleData = LocaleData {
+        months: [
+            "января",
+            "февраля",
+            "марта",
+            "апреля",
+            "мая",
+            "июня",
+            "июля",
+            "августа",
+            "сентября",
+            "октября",
+            "ноября",
+            "декабря",
+        ],
+        standalone_months: [
+            "январь",
+            "февраль",
+            "март",
+            "апрель",
+            "май",
+            "июнь",
+            "июль",
+            "август",
+            "сентябрь",
+            "октябрь",
+            "ноябрь",
+            "декабрь",
+        ],
+        months_short: [
+            "янв.",
+            "февр.",
+            "мар.",
+            "апр.",
+            "мая",
+            "июн.",
+            "июл.",
+            "авг.",
+            "сент.",
+            "окт.",
+            "нояб.",
+            "дек.",
+        ],
+        months_narrow: ["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"],
+        weekdays: [
+            "понедельник",
+            "вторник",
+            "среда",
+            "четверг",
+            "пятница",
+            "суббота",
+            "воскресенье",
+        ],
+        weekdays_short: ["пн", "вт", "ср", "чт", "пт", "сб", "вс"],
+        weekdays_narrow: ["П", "В", "С", "Ч", "П", "С", "В"],
+        eras: NO_ERAS,
+        month_year: "{standalone_month} {year} г.",
+        full_date: "{weekday}, {day} {month} {year} г.",
+        day_month: "{day} {month}",
+        ..EN
+    };
+
+    pub const SV: LocaleData = LocaleData {
+        months: SV_MONTHS,
+        standalone_months: SV_MONTHS,
+        months_short: [
+            "jan.", "feb.", "mars", "apr.", "maj", "juni", "juli", "aug.", "sep.", "okt.", "nov.",
+            "dec.",
+        ],
+        months_narrow: LATIN_NARROW_MONTHS,
+        weekdays: [
+            "måndag", "tisdag", "onsdag", "torsdag", "fredag", "lördag", "söndag",
+        ],
+        weekdays_short: ["mån", "tis", "ons", "tors", "fre", "lör", "sön"],
+        weekdays_narrow: ["M", "T", "O", "T", "F", "L", "S"],
+        eras: NO_ERAS,
+        full_date: "{weekday} {day} {month} {year}",
+        day_month: "{day} {month}",
+        ..EN
+    };
+
+    const SV_MONTHS: [&str; 12] = [
+        "januari",
+        "februari",
+        "mars",
+        "april",
+        "maj",
+        "juni",
+        "juli",
+        "augusti",
+        "september",
+        "oktober",
+        "november",
+        "december",
+    ];
+
+    pub const TH: LocaleData = LocaleData {
+        months: TH_MONTHS,
+        standalone_months: TH_MONTHS,
+        months_short: TH_MONTHS_SHORT,
+        months_narrow: TH_MONTHS_SHORT,
+        weekdays: [
+            "วันจันทร์",
+            "วันอังคาร",
+            "วันพุธ",
+            "วันพฤหัสบดี",
+            "วันศุกร์",
+            "วันเสาร์",
+            "วันอาทิตย์",
+        ],
+        weekdays_short: ["จ.", "อ.", "พ.", "พฤ.", "ศ.", "ส.", "อา."],
+        weekdays_narrow: ["จ", "อ", "พ", "พฤ", "ศ", "ส", "อา"],
+        eras: Eras {
+            buddhist: Some("พ.ศ."),
+            ..NO_ERAS
+        },
+        full_date: "{weekday}ที่ {day} {month} {year}",
+        day_month: "{day} {month}",
+        ..EN
+    };
+
+    const TH_MONTHS: [&str; 12] = [
+        "มกราคม",
+        "กุมภาพันธ์",
+        "มีนาคม",
+        "เมษายน",
+        "พฤษภาคม",
+        "มิถุนายน",
+        "กรกฎาคม",
+        "สิงหาคม",
+        "กันยายน",
+        "ตุลาคม",
+        "พฤศจิกายน",
+        "ธันวาคม",
+    ];
+
+    const TH_MONTHS_SHORT: [&str; 12] = [
+        "ม.ค.",
+        "ก.พ.",
+        "มี.ค.",
+        "เม.ย.",
+        "พ.ค.",
+        "มิ.ย.",
+        "ก.ค.",
+        "ส.ค.",
+        "ก.ย.",
+        "ต.ค.",
+        "พ.ย.",
+        "ธ.ค.",
+    ];
+
+    pub const ZH: LocaleData = LocaleData {
+        months: ZH_MONTHS,
+        standalone_months: [
+            "一月",
+            "二月",
+            "三月",
+            "四月",
+            "五月",
+            "六月",
+            "七月",
+            "八月",
+            "九月",
+            "十月",
+            "十一月",
+            "十二月",
+        ],
+        months_short: ZH_MONTHS,
+        months_narrow: NUMERIC_MONTHS,
+        weekdays: [
+            "星期一",
+            "星期二",
+            "星期三",
+            "星期四",
+            "星期五",
+            "星期六",
+            "星期日",
+        ],
+        weekdays_short: ["周一", "周二", "周三", "周四", "周五", "周六", "周日"],
+        weekdays_narrow: ["一", "二", "三", "四", "五", "六", "日"],
+        eras: NO_ERAS,
+        month_year: "{year}年{month}",
+        full_date: "{year}年{month}{day}日{weekday}",
+        day_month: "{month}{day}日",
+        year: "{year}年",
+        ..EN
+    };
+
+    const ZH_MONTHS: [&str; 12] = [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ];
+}
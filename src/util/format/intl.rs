@@ -0,0 +1,200 @@
+use chrono::{NaiveDate, Weekday};
+use js_sys::*;
+use wasm_bindgen::prelude::*;
+
+use super::Width;
+use crate::util::formatters::{self, Options};
+use crate::util::{intl, locale};
+use crate::Error;
+
+pub fn number(value: i64, locale: &str) -> Result<String, Error> {
+    string(formatters::number_format(locale)?.format(value as f64))
+}
+
+pub fn year(date: NaiveDate, calendar: &str, locale: &str) -> Result<String, Error> {
+    format(
+        date,
+        &[("year", "numeric")],
+        &with_calendar(locale, calendar),
+    )
+}
+
+pub fn weekday(weekday: Weekday, width: Width, locale: &str) -> Result<String, Error> {
+    format(
+        NaiveDate::from_isoywd(1970, 1, weekday),
+        weekday_options(width),
+        locale,
+    )
+}
+
+pub fn month(
+    date: NaiveDate,
+    width: Width,
+    standalone: bool,
+    calendar: &str,
+    locale: &str,
+) -> Result<String, Error> {
+    let locale = with_calendar(locale, calendar);
+
+    if standalone {
+        return format(date, month_options(width, false), &locale);
+    }
+
+    // Format the month along with a day, and pick out the month part
+    formatter(month_options(width, true), &locale)?
+        .format_to_parts(&js_date(date))
+        .iter()
+        .find(|part| {
+            Reflect::get(part, &JsValue::from("type"))
+                .ok()
+                .and_then(|value| value.as_string())
+                .as_deref()
+                == Some("month")
+        })
+        .and_then(|part| {
+            Reflect::get(&part, &JsValue::from("value"))
+                .ok()
+                .and_then(|value| value.as_string())
+        })
+        .ok_or_else(|| Error::Intl(String::from("no month in the formatted parts")))
+}
+
+pub fn month_and_year(date: NaiveDate, calendar: &str, locale: &str) -> Result<String, Error> {
+    format(
+        date,
+        &[("month", "long"), ("year", "numeric")],
+        &with_calendar(locale, calendar),
+    )
+}
+
+pub fn month_range(
+    start: NaiveDate,
+    end: NaiveDate,
+    calendar: &str,
+    locale: &str,
+) -> Result<String, Error> {
+    let formatter = formatter(
+        &[("month", "long"), ("year", "numeric")],
+        &with_calendar(locale, calendar),
+    )?;
+
+    if let Some(range) = formatter
+        .format_range(&js_date(start), &js_date(end))
+        .ok()
+        .and_then(|range| range.as_string())
+    {
+        return Ok(range);
+    }
+
+    let start = string(formatter.format(&js_date(start)))?;
+    let end = string(formatter.format(&js_date(end)))?;
+
+    Ok(if start == end {
+        start
+    } else {
+        format!("{} – {}", start, end)
+    })
+}
+
+pub fn date(date: NaiveDate, calendar: &str, locale: &str) -> Result<String, Error> {
+    format(
+        date,
+        &[
+            ("weekday", "long"),
+            ("month", "long"),
+            ("day", "numeric"),
+            ("year", "numeric"),
+        ],
+        &with_calendar(locale, calendar),
+    )
+}
+
+pub fn day_annotation(date: NaiveDate, calendar: &str, locale: &str) -> Result<String, Error> {
+    let locale = with_calendar(locale, calendar);
+    let day = format(
+        date,
+        &[("day", "numeric")],
+        &locale::with_unicode_keyword(&locale, "nu", "latn"),
+    )?;
+
+    if day.trim() == "1" {
+        format(date, &[("month", "short"), ("day", "numeric")], &locale)
+    } else {
+        format(date, &[("day", "numeric")], &locale)
+    }
+}
+
+pub fn supports_locale(locale: &str) -> bool {
+    let options = Object::new();
+    if Reflect::set(
+        &options,
+        &JsValue::from("localeMatcher"),
+        &JsValue::from("lookup"),
+    )
+    .is_err()
+    {
+        return false;
+    }
+
+    let supported =
+        intl::DateTimeFormat::supported_locales_of(&Array::of1(&JsValue::from(locale)), &options);
+
+    matches!(supported, Ok(supported) if supported.length() > 0)
+}
+
+pub fn resolved_locale(locale: &str) -> Result<String, Error> {
+    Reflect::get(
+        &formatter(&[], locale)?.resolved_options(),
+        &JsValue::from("locale"),
+    )
+    .map_err(Error::from_js)?
+    .as_string()
+    .ok_or_else(|| Error::Intl(String::from("no locale in the resolved options")))
+}
+
+// Helpers
+
+fn with_calendar(locale: &str, calendar: &str) -> String {
+    locale::with_unicode_keyword(locale, "ca", calendar)
+}
+
+// The options are spelled out per width, as the formatters are cached by `'static` options
+
+fn weekday_options(width: Width) -> &'static Options {
+    match width {
+        Width::Narrow => &[("weekday", "narrow")],
+        Width::Short => &[("weekday", "short")],
+        Width::Long => &[("weekday", "long")],
+    }
+}
+
+fn month_options(width: Width, with_day: bool) -> &'static Options {
+    match (width, with_day) {
+        (Width::Narrow, false) => &[("month", "narrow")],
+        (Width::Short, false) => &[("month", "short")],
+        (Width::Long, false) => &[("month", "long")],
+        (Width::Narrow, true) => &[("month", "narrow"), ("day", "numeric")],
+        (Width::Short, true) => &[("month", "short"), ("day", "numeric")],
+        (Width::Long, true) => &[("month", "long"), ("day", "numeric")],
+    }
+}
+
+fn format(date: NaiveDate, options: &'static Options, locale: &str) -> Result<String, Error> {
+    string(formatter(options, locale)?.format(&js_date(date)))
+}
+
+fn formatter(options: &'static Options, locale: &str) -> Result<intl::DateTimeFormat, Error> {
+    formatters::date_time_format(locale, options)
+}
+
+fn string(value: JsString) -> Result<String, Error> {
+    value
+        .as_string()
+        .ok_or_else(|| Error::Intl(String::from("formatted value isn't a string")))
+}
+
+fn js_date(date: NaiveDate) -> Date {
+    let datetime = date.and_hms(12, 0, 0);
+
+    Date::new(&JsValue::from(datetime.timestamp_millis() as f64))
+}
@@ -0,0 +1,98 @@
+//! Shared `Intl` formatters, keyed by locale and options. Constructing a formatter is far more
+//! expensive than formatting with one, and the views format many values with the same few.
+
+use js_sys::*;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::thread::LocalKey;
+use wasm_bindgen::prelude::*;
+
+use crate::util::intl;
+use crate::Error;
+
+pub type Options = [(&'static str, &'static str)];
+
+// Keyed by locale, then by options. The options are `'static`, so that a lookup borrows both and
+// only a miss allocates.
+type Cache<T> = RefCell<HashMap<String, HashMap<&'static Options, Result<T, Error>>>>;
+
+// Locales come from the views' configuration, and options from a fixed set, so the caches stay
+// small. This only guards against unbounded growth when locales are generated dynamically.
+const MAX_LOCALES: usize = 256;
+
+thread_local! {
+    static ENABLED: Cell<bool> = const { Cell::new(true) };
+    static DATE_TIME_FORMATS: Cache<intl::DateTimeFormat> = RefCell::new(HashMap::new());
+    static NUMBER_FORMATS: Cache<intl::NumberFormat> = RefCell::new(HashMap::new());
+}
+
+pub fn date_time_format(
+    locale: &str,
+    options: &'static Options,
+) -> Result<intl::DateTimeFormat, Error> {
+    cached(&DATE_TIME_FORMATS, locale, options, |locales| {
+        // Dates are passed at noon UTC, so they're formatted in UTC to keep the day in any time
+        // zone
+        let opts = Object::new();
+        Reflect::set(&opts, &JsValue::from("timeZone"), &JsValue::from("UTC"))?;
+        for (key, value) in options {
+            Reflect::set(&opts, &JsValue::from(*key), &JsValue::from(*value))?;
+        }
+
+        intl::DateTimeFormat::new(locales, &opts)
+    })
+}
+
+/// A number formatter for the locale that doesn't group digits, so years render as e.g. "2021".
+pub fn number_format(locale: &str) -> Result<intl::NumberFormat, Error> {
+    cached(&NUMBER_FORMATS, locale, &[], |locales| {
+        let opts = Object::new();
+        Reflect::set(&opts, &JsValue::from("useGrouping"), &JsValue::from(false))?;
+
+        intl::NumberFormat::new(locales, &opts)
+    })
+}
+
+/// Turns the caches on or off. Without them, every value is formatted with a new formatter, which
+/// is only useful to measure what the caches save, as the benchmarks do.
+#[cfg(feature = "bench")]
+pub fn set_formatter_caching(enabled: bool) {
+    ENABLED.with(|cell| cell.set(enabled));
+    DATE_TIME_FORMATS.with(|cache| cache.borrow_mut().clear());
+    NUMBER_FORMATS.with(|cache| cache.borrow_mut().clear());
+}
+
+// Failures are cached too, as an invalid locale would otherwise throw for every value formatted
+fn cached<T: Clone>(
+    cache: &'static LocalKey<Cache<T>>,
+    locale: &str,
+    options: &'static Options,
+    construct: impl FnOnce(&Array) -> Result<T, JsValue>,
+) -> Result<T, Error> {
+    if !ENABLED.with(Cell::get) {
+        return construct(&Array::of1(&JsValue::from(locale))).map_err(Error::from_js);
+    }
+
+    cache.with(|cache| {
+        if let Some(formatter) = cache
+            .borrow()
+            .get(locale)
+            .and_then(|formatters| formatters.get(options))
+        {
+            return formatter.clone();
+        }
+
+        let formatter = construct(&Array::of1(&JsValue::from(locale))).map_err(Error::from_js);
+
+        let mut cache = cache.borrow_mut();
+        if cache.len() >= MAX_LOCALES && !cache.contains_key(locale) {
+            cache.clear();
+        }
+        cache
+            .entry(String::from(locale))
+            .or_default()
+            .insert(options, formatter.clone());
+
+        formatter
+    })
+}
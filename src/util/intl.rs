@@ -5,6 +5,25 @@ use wasm_bindgen::prelude::*;
 // License: APACHE-2.0 or MIT
 // TODO: Use js_sys::Intl directly once https://github.com/rustwasm/wasm-bindgen/pull/2506 is merged
 
+/// Whether the `Intl` API can be used. It never is on native targets or without the `intl`
+/// feature, and on the web only where the JavaScript environment provides it.
+pub fn is_available() -> bool {
+    #[cfg(all(target_arch = "wasm32", feature = "intl"))]
+    {
+        thread_local! {
+            static AVAILABLE: bool =
+                Reflect::has(&global(), &JsValue::from("Intl")).unwrap_or(false);
+        }
+
+        AVAILABLE.with(|available| *available)
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "intl")))]
+    {
+        false
+    }
+}
+
 // Intl
 #[wasm_bindgen]
 extern "C" {
@@ -13,8 +32,8 @@ extern "C" {
     /// will be validated as structurally valid language tags.
     ///
     /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/getCanonicalLocales)
-    #[wasm_bindgen(js_name = getCanonicalLocales, js_namespace = Intl)]
-    pub fn get_canonical_locales(s: &JsValue) -> Array;
+    #[wasm_bindgen(catch, js_name = getCanonicalLocales, js_namespace = Intl)]
+    pub fn get_canonical_locales(s: &JsValue) -> Result<Array, JsValue>;
 }
 
 // Intl.Collator
@@ -76,8 +95,8 @@ extern "C" {
     /// that enable language-sensitive date and time formatting.
     ///
     /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat)
-    #[wasm_bindgen(constructor, js_namespace = Intl)]
-    pub fn new(locales: &Array, options: &Object) -> DateTimeFormat;
+    #[wasm_bindgen(catch, constructor, js_namespace = Intl)]
+    pub fn new(locales: &Array, options: &Object) -> Result<DateTimeFormat, JsValue>;
 
     /// The Intl.DateTimeFormat.prototype.format property returns a getter function that
     /// formats a date according to the locale and formatting options of this
@@ -94,6 +113,18 @@ extern "C" {
     #[wasm_bindgen(method, js_class = "Intl.DateTimeFormat", js_name = formatToParts)]
     pub fn format_to_parts(this: &DateTimeFormat, date: &Date) -> Array;
 
+    /// The `Intl.DateTimeFormat.prototype.formatRange()` method formats a date range
+    /// in the most concise way based on the locale and options provided when
+    /// instantiating DateTimeFormat object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat/formatRange)
+    #[wasm_bindgen(catch, method, js_class = "Intl.DateTimeFormat", js_name = formatRange)]
+    pub fn format_range(
+        this: &DateTimeFormat,
+        start_date: &Date,
+        end_date: &Date,
+    ) -> Result<JsString, JsValue>;
+
     /// The `Intl.DateTimeFormat.prototype.resolvedOptions()` method returns a new
     /// object with properties reflecting the locale and date and time formatting
     /// options computed during initialization of this DateTimeFormat object.
@@ -108,8 +139,8 @@ extern "C" {
     /// locale.
     ///
     /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat/supportedLocalesOf)
-    #[wasm_bindgen(static_method_of = DateTimeFormat, js_namespace = Intl, js_name = supportedLocalesOf)]
-    pub fn supported_locales_of(locales: &Array, options: &Object) -> Array;
+    #[wasm_bindgen(catch, static_method_of = DateTimeFormat, js_namespace = Intl, js_name = supportedLocalesOf)]
+    pub fn supported_locales_of(locales: &Array, options: &Object) -> Result<Array, JsValue>;
 }
 
 // Intl.NumberFormat
@@ -127,8 +158,8 @@ extern "C" {
     /// that enable language sensitive number formatting.
     ///
     /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat)
-    #[wasm_bindgen(constructor, js_namespace = Intl)]
-    pub fn new(locales: &Array, options: &Object) -> NumberFormat;
+    #[wasm_bindgen(catch, constructor, js_namespace = Intl)]
+    pub fn new(locales: &Array, options: &Object) -> Result<NumberFormat, JsValue>;
 
     /// The Intl.NumberFormat.prototype.format property returns a getter function that
     /// formats a number according to the locale and formatting options of this
@@ -203,3 +234,71 @@ extern "C" {
     #[wasm_bindgen(static_method_of = PluralRules, js_namespace = Intl, js_name = supportedLocalesOf)]
     pub fn supported_locales_of(locales: &Array, options: &Object) -> Array;
 }
+
+// Intl.Locale
+#[wasm_bindgen]
+extern "C" {
+    /// The `Intl.Locale` object is a standard built-in property of the Intl object
+    /// that represents a Unicode locale identifier.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Locale)
+    #[wasm_bindgen(extends = Object, js_namespace = Intl, typescript_type = "Intl.Locale")]
+    #[derive(Clone, Debug)]
+    pub type Locale;
+
+    /// The `Intl.Locale` constructor is a standard built-in property of the Intl object
+    /// that represents a Unicode locale identifier. Throws if the tag is malformed, or if
+    /// `Intl.Locale` is not supported.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Locale/Locale)
+    #[wasm_bindgen(catch, constructor, js_namespace = Intl)]
+    pub fn new(tag: &str) -> Result<Locale, JsValue>;
+
+    /// The `Intl.Locale.prototype.maximize()` method gets the most likely values
+    /// for the language, script, and region of the locale based on existing values.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Locale/maximize)
+    #[wasm_bindgen(method, js_class = "Intl.Locale")]
+    pub fn maximize(this: &Locale) -> Locale;
+
+    /// The `Intl.Locale.prototype.region` accessor property returns the region
+    /// of the world (usually a country) associated with the locale.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Locale/region)
+    #[wasm_bindgen(method, getter, js_class = "Intl.Locale")]
+    pub fn region(this: &Locale) -> Option<String>;
+
+    /// The `Intl.Locale.prototype.getWeekInfo()` method returns a `weekInfo` object
+    /// with the properties `firstDay`, `weekend` and `minimalDays` for this locale.
+    /// Throws if not supported.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Locale/getWeekInfo)
+    #[wasm_bindgen(catch, method, js_class = "Intl.Locale", js_name = getWeekInfo)]
+    pub fn get_week_info(this: &Locale) -> Result<Object, JsValue>;
+
+    /// The `Intl.Locale.prototype.weekInfo` accessor property is the older, deprecated
+    /// form of `getWeekInfo()`. Returns `undefined` if not supported.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Locale/getWeekInfo)
+    #[wasm_bindgen(method, getter, js_class = "Intl.Locale", js_name = weekInfo)]
+    pub fn week_info(this: &Locale) -> JsValue;
+}
+
+// Intl.Locale text info
+#[wasm_bindgen]
+extern "C" {
+    /// The `Intl.Locale.prototype.getTextInfo()` method returns a `textInfo` object
+    /// with the property `direction`, which denotes the type of text ordering for this
+    /// locale. Throws if not supported.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Locale/getTextInfo)
+    #[wasm_bindgen(catch, method, js_class = "Intl.Locale", js_name = getTextInfo)]
+    pub fn get_text_info(this: &Locale) -> Result<Object, JsValue>;
+
+    /// The `Intl.Locale.prototype.textInfo` accessor property is the older, deprecated
+    /// form of `getTextInfo()`. Returns `undefined` if not supported.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Locale/getTextInfo)
+    #[wasm_bindgen(method, getter, js_class = "Intl.Locale", js_name = textInfo)]
+    pub fn text_info(this: &Locale) -> JsValue;
+}
@@ -0,0 +1,35 @@
+/// Sets a keyword of the `-u-` extension of a BCP 47 language tag, replacing any value already
+/// set, e.g. `("th-TH-u-nu-thai", "ca", "buddhist")` gives `th-TH-u-ca-buddhist-nu-thai`.
+pub fn with_unicode_keyword(locale: &str, key: &str, value: &str) -> String {
+    let mut subtags: Vec<&str> = locale.split(['-', '_']).collect();
+
+    match subtags
+        .iter()
+        .position(|subtag| subtag.eq_ignore_ascii_case("u"))
+    {
+        Some(extension) => {
+            let keyword = subtags[extension + 1..]
+                .iter()
+                .take_while(|subtag| subtag.len() > 1)
+                .position(|subtag| subtag.eq_ignore_ascii_case(key))
+                .map(|keyword| extension + 1 + keyword);
+
+            match keyword {
+                // Keyword values are one or more subtags of three to eight characters
+                Some(keyword) => {
+                    let end = subtags[keyword + 1..]
+                        .iter()
+                        .position(|subtag| subtag.len() <= 2)
+                        .map_or(subtags.len(), |end| keyword + 1 + end);
+                    subtags.splice(keyword + 1..end, Some(value));
+                }
+                None => {
+                    subtags.splice(extension + 1..extension + 1, [key, value]);
+                }
+            }
+        }
+        None => subtags.extend(["u", key, value]),
+    }
+
+    subtags.join("-")
+}
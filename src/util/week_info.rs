@@ -0,0 +1,240 @@
+use chrono::Weekday;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WeekInfo {
+    pub first_day: Weekday,
+    pub weekend: Vec<Weekday>,
+    pub minimal_days: u32,
+}
+
+impl WeekInfo {
+    pub fn is_weekend(&self, weekday: Weekday) -> bool {
+        self.weekend.contains(&weekday)
+    }
+}
+
+// Like the formatters, only guards against unbounded growth with dynamically generated locales
+const MAX_ENTRIES: usize = 256;
+
+thread_local! {
+    static WEEK_INFOS: RefCell<HashMap<String, WeekInfo>> = RefCell::new(HashMap::new());
+}
+
+/// Week conventions for the given locale, as reported by `Intl.Locale` where supported, or
+/// from a built-in subset of the CLDR week data otherwise. Looked up once per locale, as every
+/// view asks for them on every render.
+pub fn for_locale(locale: &str) -> WeekInfo {
+    WEEK_INFOS.with(|cache| {
+        if let Some(week_info) = cache.borrow().get(locale) {
+            return week_info.clone();
+        }
+
+        let week_info = from_intl(locale).unwrap_or_else(|| from_cldr(locale));
+
+        let mut cache = cache.borrow_mut();
+        if cache.len() >= MAX_ENTRIES {
+            cache.clear();
+        }
+        cache.insert(String::from(locale), week_info.clone());
+
+        week_info
+    })
+}
+
+fn from_intl(locale: &str) -> Option<WeekInfo> {
+    use crate::util::intl;
+    use js_sys::*;
+    use wasm_bindgen::{prelude::*, JsCast};
+
+    if !intl::is_available() {
+        return None;
+    }
+
+    let locale = intl::Locale::new(locale).ok()?;
+    let week_info = match locale.get_week_info() {
+        Ok(week_info) => week_info,
+        Err(_) => locale.week_info().dyn_into::<Object>().ok()?,
+    };
+
+    let first_day = Reflect::get(&week_info, &JsValue::from("firstDay"))
+        .ok()?
+        .as_f64()?;
+    let weekend = Reflect::get(&week_info, &JsValue::from("weekend"))
+        .ok()?
+        .dyn_into::<Array>()
+        .ok()?
+        .iter()
+        .map(|day| day.as_f64().and_then(|day| weekday_from_number(day as u32)))
+        .collect::<Option<Vec<_>>>()?;
+    let minimal_days = Reflect::get(&week_info, &JsValue::from("minimalDays"))
+        .ok()?
+        .as_f64()?;
+
+    Some(WeekInfo {
+        first_day: weekday_from_number(first_day as u32)?,
+        weekend,
+        minimal_days: minimal_days as u32,
+    })
+}
+
+// ISO weekday numbers, as used by `Intl.Locale`: 1 is Monday and 7 is Sunday
+fn weekday_from_number(number: u32) -> Option<Weekday> {
+    Some(match number {
+        1 => Weekday::Mon,
+        2 => Weekday::Tue,
+        3 => Weekday::Wed,
+        4 => Weekday::Thu,
+        5 => Weekday::Fri,
+        6 => Weekday::Sat,
+        7 => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+fn from_cldr(locale: &str) -> WeekInfo {
+    let region = region(locale);
+    let region = region.as_deref().unwrap_or("001");
+
+    WeekInfo {
+        first_day: first_day_override(locale).unwrap_or_else(|| cldr::first_day(region)),
+        weekend: cldr::weekend(region),
+        minimal_days: cldr::minimal_days(region),
+    }
+}
+
+// The region subtag of a BCP 47 language tag, or the most likely region for its language
+// according to `Intl.Locale` where supported, or the built-in subset of CLDR otherwise
+fn region(locale: &str) -> Option<String> {
+    let subtags: Vec<&str> = locale
+        .split(['-', '_'])
+        .take_while(|subtag| subtag.len() > 1)
+        .collect();
+
+    subtags
+        .iter()
+        .skip(1)
+        .find(|subtag| {
+            (subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+                || (subtag.len() == 3 && subtag.chars().all(|c| c.is_ascii_digit()))
+        })
+        .map(|subtag| subtag.to_ascii_uppercase())
+        .or_else(|| likely_region_from_intl(locale))
+        .or_else(|| {
+            subtags
+                .first()
+                .and_then(|language| cldr::likely_region(&language.to_ascii_lowercase()))
+                .map(String::from)
+        })
+}
+
+fn likely_region_from_intl(locale: &str) -> Option<String> {
+    use crate::util::intl;
+
+    if !intl::is_available() {
+        return None;
+    }
+
+    intl::Locale::new(locale).ok()?.maximize().region()
+}
+
+// The first day of week set with the `-u-fw-` extension, e.g. `en-GB-u-fw-sun`
+fn first_day_override(locale: &str) -> Option<Weekday> {
+    let subtags: Vec<String> = locale
+        .split(['-', '_'])
+        .map(|subtag| subtag.to_ascii_lowercase())
+        .collect();
+
+    let extension = subtags.iter().position(|subtag| subtag == "u")?;
+    let keyword = subtags[extension..]
+        .iter()
+        .position(|subtag| subtag == "fw")?;
+
+    subtags.get(extension + keyword + 1)?.parse().ok()
+}
+
+mod cldr {
+    use chrono::Weekday;
+
+    // From the CLDR supplemental week data. Regions not listed use the world defaults.
+
+    pub fn first_day(region: &str) -> Weekday {
+        match region {
+            "AG" | "AS" | "BD" | "BR" | "BS" | "BT" | "BW" | "BZ" | "CA" | "CN" | "CO" | "DM"
+            | "DO" | "ET" | "GT" | "GU" | "HK" | "HN" | "ID" | "IL" | "IN" | "JM" | "JP" | "KE"
+            | "KH" | "KR" | "LA" | "MH" | "MM" | "MO" | "MT" | "MX" | "MZ" | "NI" | "NP" | "PA"
+            | "PE" | "PH" | "PK" | "PR" | "PT" | "PY" | "SA" | "SG" | "SV" | "TH" | "TT" | "TW"
+            | "UM" | "US" | "VE" | "VI" | "WS" | "YE" | "ZA" | "ZW" => Weekday::Sun,
+            "AE" | "AF" | "BH" | "DJ" | "DZ" | "EG" | "IQ" | "IR" | "JO" | "KW" | "LY" | "OM"
+            | "QA" | "SD" | "SY" => Weekday::Sat,
+            "MV" => Weekday::Fri,
+            _ => Weekday::Mon,
+        }
+    }
+
+    pub fn weekend(region: &str) -> Vec<Weekday> {
+        match region {
+            "AE" | "BH" | "DZ" | "EG" | "IL" | "IQ" | "JO" | "KW" | "LY" | "OM" | "QA" | "SA"
+            | "SD" | "SY" | "YE" => vec![Weekday::Fri, Weekday::Sat],
+            "AF" => vec![Weekday::Thu, Weekday::Fri],
+            "IR" => vec![Weekday::Fri],
+            "IN" | "UG" => vec![Weekday::Sun],
+            _ => vec![Weekday::Sat, Weekday::Sun],
+        }
+    }
+
+    pub fn minimal_days(region: &str) -> u32 {
+        match region {
+            "AD" | "AN" | "AT" | "AX" | "BE" | "BG" | "CH" | "CZ" | "DE" | "DK" | "EE" | "ES"
+            | "FI" | "FJ" | "FO" | "FR" | "GB" | "GF" | "GG" | "GI" | "GP" | "GR" | "HU" | "IE"
+            | "IM" | "IS" | "IT" | "JE" | "LI" | "LT" | "LU" | "MC" | "MQ" | "NL" | "NO" | "PL"
+            | "RE" | "RU" | "SE" | "SJ" | "SK" | "SM" | "VA" => 4,
+            _ => 1,
+        }
+    }
+
+    // From the CLDR likely subtags, for languages commonly used without a region
+    pub fn likely_region(language: &str) -> Option<&'static str> {
+        Some(match language {
+            "ar" => "EG",
+            "bg" => "BG",
+            "bn" => "BD",
+            "cs" => "CZ",
+            "da" => "DK",
+            "de" => "DE",
+            "el" => "GR",
+            "en" => "US",
+            "es" => "ES",
+            "et" => "EE",
+            "fa" => "IR",
+            "fi" => "FI",
+            "fr" => "FR",
+            "he" | "iw" => "IL",
+            "hi" => "IN",
+            "hu" => "HU",
+            "id" => "ID",
+            "it" => "IT",
+            "ja" => "JP",
+            "ko" => "KR",
+            "lt" => "LT",
+            "lv" => "LV",
+            "ms" => "MY",
+            "nb" | "nn" | "no" => "NO",
+            "nl" => "NL",
+            "pl" => "PL",
+            "pt" => "BR",
+            "ro" => "RO",
+            "ru" => "RU",
+            "sk" => "SK",
+            "sv" => "SE",
+            "th" => "TH",
+            "tr" => "TR",
+            "uk" => "UA",
+            "ur" => "PK",
+            "vi" => "VN",
+            "zh" => "CN",
+            _ => return None,
+        })
+    }
+}
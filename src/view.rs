@@ -3,7 +3,8 @@ mod months;
 mod weeks;
 mod years;
 
+pub use crate::core::{DayState, Intersection, Week, WeekNumbering};
 pub use month::MonthView;
-pub use months::MonthsView;
+pub use months::{MonthForm, MonthStyle, MonthsLayout, MonthsView};
 pub use weeks::WeeksView;
 pub use years::YearsView;
@@ -1,106 +1,188 @@
-use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use chrono::{NaiveDate, Weekday};
 use seed::{prelude::*, *};
 use std::rc::Rc;
 
+use crate::calendar::Calendar;
+use crate::core::{DayState, GridDay, GridWeek, Intersection, MonthGrid, Selection, WeekNumbering};
+use crate::html;
+use crate::locale::Locale;
+use crate::util::direction::{self, Direction};
+use crate::util::format::{self, Width};
+use crate::Error;
+
+const MAX_SKIPPED_DAYS: usize = 366;
+
+type DayRenderer<Ms> = Rc<dyn Fn(NaiveDate, DayState) -> Node<Ms>>;
+
 pub struct MonthView<Ms> {
-    pub(crate) year: i32,
-    pub(crate) month: u32,
-    pub(crate) selection: Selection,
+    pub(crate) grid: MonthGrid,
+    pub(crate) day_renderer: Option<DayRenderer<Ms>>,
+    pub(crate) focused: Option<NaiveDate>,
     pub(crate) on_click: Option<Rc<dyn Fn(NaiveDate) -> Ms>>,
-    pub(crate) first_weekday: Weekday,
+    pub(crate) on_focus_change: Option<Rc<dyn Fn(NaiveDate) -> Ms>>,
+    pub(crate) on_navigate: Option<Rc<dyn Fn(i32, u32) -> Ms>>,
+    pub(crate) on_title_click: Option<Rc<dyn Fn() -> Ms>>,
     pub(crate) show_week_numbers: bool,
     pub(crate) show_weekdays: bool,
-    pub(crate) locale: String,
-}
-
-#[derive(Copy, Clone)]
-pub(crate) enum Selection {
-    None,
-    Single(NaiveDate),
-    Range(NaiveDate, NaiveDate),
-}
-
-impl Selection {
-    fn intersects(self, date: NaiveDate) -> Option<Intersection> {
-        use Intersection::*;
-        Some(match self {
-            Selection::Single(selected) if selected == date => All,
-            Selection::Range(start, _) if start == date => Start,
-            Selection::Range(_, end) if end == date => End,
-            Selection::Range(start, end) if date > start && date < end => Inside,
-            _ => return None,
-        })
-    }
-
-    fn intersects_range(self, start: NaiveDate, end: NaiveDate) -> Option<Intersection> {
-        use Intersection::*;
-        Some(match self {
-            Selection::Single(selected) if selected >= start && selected <= end => All,
-            Selection::Range(sel_start, sel_end) if sel_start == start && sel_end == end => All,
-            Selection::Range(sel_start, _) if sel_start >= start && sel_start <= end => Start,
-            Selection::Range(_, sel_end) if sel_end >= start && sel_end <= end => End,
-            Selection::Range(sel_start, sel_end) if sel_start <= start && sel_end >= end => Inside,
-            _ => return None,
-        })
-    }
-}
-
-enum Intersection {
-    Start,
-    Inside,
-    End,
-    All,
+    pub(crate) show_header: bool,
+    pub(crate) secondary_calendar: Option<String>,
+    pub(crate) navigation_labels: Option<(String, String)>,
 }
 
 impl<Ms: 'static> MonthView<Ms> {
     // Constructor
 
     pub fn new(year: i32, month: u32) -> Self {
+        MonthView::from_grid(MonthGrid::new(year, month))
+    }
+
+    pub(crate) fn from_grid(grid: MonthGrid) -> Self {
         MonthView {
-            year,
-            month,
-            selection: Selection::None,
+            grid,
+            day_renderer: None,
+            focused: None,
             on_click: None,
-            first_weekday: Weekday::Mon,
+            on_focus_change: None,
+            on_navigate: None,
+            on_title_click: None,
             show_week_numbers: false,
             show_weekdays: false,
-            locale: String::from("en-US"),
+            show_header: false,
+            secondary_calendar: None,
+            navigation_labels: None,
         }
     }
 
+    /// Like `new`, but fails if the month doesn't exist.
+    pub fn try_new(year: i32, month: u32) -> Result<Self, Error> {
+        MonthGrid::try_new(year, month)?;
+
+        Ok(MonthView::new(year, month))
+    }
+
     // Builder functions
 
     pub fn with_selected(mut self, date: NaiveDate) -> Self {
-        self.selection = Selection::Single(date);
+        self.grid = self.grid.with_selection(Selection::Single(date));
         self
     }
 
     pub fn with_selection(mut self, start: NaiveDate, end: NaiveDate) -> Self {
-        self.selection = if start == end {
-            Selection::Single(start)
-        } else if start > end {
-            Selection::Range(end, start)
-        } else {
-            Selection::Range(start, end)
-        };
+        self.grid = self.grid.with_selection(Selection::between(start, end));
         self
     }
 
-    pub fn maybe_with_selection(self, start: Option<NaiveDate>, end: Option<NaiveDate>) -> Self {
-        match (start, end) {
-            (None, None) => self,
-            (Some(date), None) | (None, Some(date)) => self.with_selected(date),
-            (Some(start), Some(end)) => self.with_selection(start, end),
+    pub fn maybe_with_selection(
+        mut self,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Self {
+        if start.is_some() || end.is_some() {
+            self.grid = self.grid.with_selection(Selection::from_bounds(start, end));
         }
+        self
+    }
+
+    /// Selects any number of dates, each marked `selected` on its own, as are the weeks with any
+    /// of them.
+    pub fn with_selected_dates(mut self, dates: impl IntoIterator<Item = NaiveDate>) -> Self {
+        self.grid = self.grid.with_selection(Selection::from_dates(dates));
+        self
+    }
+
+    pub fn with_min_date(mut self, date: NaiveDate) -> Self {
+        self.grid = self.grid.with_min_date(date);
+        self
+    }
+
+    pub fn with_max_date(mut self, date: NaiveDate) -> Self {
+        self.grid = self.grid.with_max_date(date);
+        self
+    }
+
+    /// Sets both the minimum and maximum date, failing if the minimum is after the maximum.
+    pub fn try_with_date_range(self, min: NaiveDate, max: NaiveDate) -> Result<Self, Error> {
+        Error::check_date_range(min, max)?;
+
+        Ok(self.with_min_date(min).with_max_date(max))
+    }
+
+    pub fn with_disabled(mut self, predicate: impl Fn(NaiveDate) -> bool + 'static) -> Self {
+        self.grid = self.grid.with_disabled(predicate);
+        self
+    }
+
+    pub fn with_today(mut self, date: NaiveDate) -> Self {
+        self.grid = self.grid.with_today(date);
+        self
+    }
+
+    pub fn with_day_renderer(
+        mut self,
+        renderer: impl Fn(NaiveDate, DayState) -> Node<Ms> + 'static,
+    ) -> Self {
+        self.day_renderer = Some(Rc::new(renderer));
+        self
+    }
+
+    /// The date that has the focus, which is reached with the tab key. The parent owns it: store
+    /// the date passed to `on_focus_change` and feed it back in here on the next render.
+    pub fn with_focused(mut self, date: NaiveDate) -> Self {
+        self.focused = Some(date);
+        self
     }
 
     pub fn with_first_weekday(mut self, weekday: Weekday) -> Self {
-        self.first_weekday = weekday;
+        self.grid = self.grid.with_first_weekday(weekday);
+        self
+    }
+
+    /// Numbers the weeks by the given scheme, which decides the first weekday too. See
+    /// `WeekNumbering`.
+    pub fn with_week_numbering(mut self, numbering: WeekNumbering) -> Self {
+        self.grid = self.grid.with_week_numbering(numbering);
+        self
+    }
+
+    pub fn with_locale(mut self, locale: impl Into<Locale>) -> Self {
+        self.grid = self.grid.with_locale(locale);
         self
     }
 
-    pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
-        self.locale = locale.into();
+    /// Like `with_locale`, but fails if the locale isn't a well-formed language tag.
+    pub fn try_with_locale(self, locale: impl AsRef<str>) -> Result<Self, Error> {
+        Ok(self.with_locale(Locale::new(locale.as_ref())?))
+    }
+
+    /// Lays out the month in the given calendar. The year and month passed to `new` and
+    /// `on_navigate` are then in that calendar too, while all other dates remain Gregorian.
+    pub fn with_calendar(mut self, calendar: Calendar) -> Self {
+        self.grid = self.grid.with_calendar(calendar);
+        self
+    }
+
+    /// Like `with_calendar`, but fails if the month doesn't exist in the calendar.
+    pub fn try_with_calendar(mut self, calendar: Calendar) -> Result<Self, Error> {
+        self.grid = self.grid.try_with_calendar(calendar)?;
+        Ok(self)
+    }
+
+    /// Annotates each day with its date in a secondary calendar, given by its BCP 47 identifier,
+    /// e.g. `Calendar::IslamicUmalqura.identifier()` or `"chinese"`, and shows the secondary
+    /// months spanned in the header. Days drawn by a custom day renderer aren't annotated.
+    pub fn with_secondary_calendar(mut self, calendar: impl Into<String>) -> Self {
+        self.secondary_calendar = Some(calendar.into());
+        self
+    }
+
+    /// Labels the buttons to the previous and next month for assistive technologies, e.g. in the
+    /// language of the locale. They're labelled "Previous month" and "Next month" by default.
+    pub fn with_navigation_labels(
+        mut self,
+        previous: impl Into<String>,
+        next: impl Into<String>,
+    ) -> Self {
+        self.navigation_labels = Some((previous.into(), next.into()));
         self
     }
 
@@ -114,143 +196,355 @@ impl<Ms: 'static> MonthView<Ms> {
         self
     }
 
+    pub fn show_header(mut self) -> Self {
+        self.show_header = true;
+        self
+    }
+
     pub fn on_click(mut self, handler: impl FnOnce(NaiveDate) -> Ms + Clone + 'static) -> Self {
         self.on_click = Some(Rc::new(move |date| handler.clone()(date)));
         self
     }
 
+    /// Called with the date to move the focus to when an arrow key, Page Up, Page Down, Home or
+    /// End is pressed. The view doesn't keep it, so pass it to `with_focused` to move the focus.
+    pub fn on_focus_change(
+        mut self,
+        handler: impl FnOnce(NaiveDate) -> Ms + Clone + 'static,
+    ) -> Self {
+        self.on_focus_change = Some(Rc::new(move |date| handler.clone()(date)));
+        self
+    }
+
+    pub fn on_navigate(mut self, handler: impl FnOnce(i32, u32) -> Ms + Clone + 'static) -> Self {
+        self.on_navigate = Some(Rc::new(move |year, month| handler.clone()(year, month)));
+        self
+    }
+
+    pub fn on_title_click(mut self, handler: impl FnOnce() -> Ms + Clone + 'static) -> Self {
+        self.on_title_click = Some(Rc::new(move || handler.clone()()));
+        self
+    }
+
     // Consumers
 
+    /// Renders nothing if the month doesn't exist.
     pub fn into_node(self) -> Node<Ms> {
-        let first_of_month = NaiveDate::from_ymd(self.year, self.month, 1);
-        let start_date = {
-            let start_date = helpers::start_of_week(first_of_month, self.first_weekday);
-
-            if first_of_month < start_date {
-                start_date - Duration::weeks(1)
-            } else {
-                start_date
-            }
-        };
+        if self.grid.validate().is_err() {
+            return empty();
+        }
 
-        let weeks = start_date.iter_weeks().take(6);
-        // .take_while(|date| date.month() <= self.month);
+        let grid = &self.grid;
+        let locale = grid.locale().as_str();
+        let first_weekday = grid.first_weekday();
+        let tabbable = grid.tabbable_date(self.focused);
+        let direction = direction::for_locale(locale);
 
         table![
-            C!["seed-calendar-month-view"],
+            C![
+                "seed-calendar-month-view",
+                IF!(grid.has_invalid_selection() => "invalid-selection"),
+            ],
             attrs! {
-                At::from("role") => "presentation",
+                At::from("role") => "grid",
+                At::AriaLabel => format::month_and_year(grid.first_of_month(), grid.calendar().identifier(), locale),
+                At::Dir => direction.as_str(),
             },
-            self.show_weekdays.then(|| {
-                thead![tr![
-                    self.show_week_numbers
-                        .then(|| th![C!["week-number"], span![]]),
-                    start_date
-                        .iter_days()
-                        .take(7)
-                        .map(|date| th![helpers::format_weekday(date.weekday(), &self.locale)])
-                ]]
+            self.on_focus_change.clone().map(|on_focus_change| {
+                self.keyboard_handler(tabbable, first_weekday, direction, on_focus_change)
             }),
-            weeks.map(|week| {
-                let days: Vec<NaiveDate> = week.iter_days().take(7).collect();
-                assert!(!days.is_empty());
-
-                tr![
-                    self.show_week_numbers.then(|| td![
-                        C!["week-number"],
-                        div![span![helpers::week_number(week, self.first_weekday)]]
-                    ]),
-                    C![match self
-                        .selection
-                        .intersects_range(*days.first().unwrap(), *days.last().unwrap())
-                    {
-                        Some(Intersection::All) => "selected",
-                        Some(Intersection::Start) => "selection-start",
-                        Some(Intersection::End) => "selection-end",
-                        Some(Intersection::Inside) => "in-selection",
-                        None => "",
-                    }],
-                    days.into_iter().map(|date| {
-                        if date.month() == self.month {
-                            let on_click = self.on_click.clone();
-
-                            td![div![button![
-                                C![match self.selection.intersects(date) {
-                                    Some(Intersection::All) => "selected",
-                                    Some(Intersection::Start) => "selection-start",
-                                    Some(Intersection::End) => "selection-end",
-                                    Some(Intersection::Inside) => "in-selection",
-                                    None => "",
-                                }],
-                                on_click.map(|on_click| ev(Ev::Click, move |_| on_click(date))),
-                                date.day()
-                            ]]]
-                        } else {
-                            td![div![date.day()]]
-                        }
-                    })
+            (self.show_header || self.show_weekdays).then(|| {
+                thead![
+                    self.show_header.then(|| self.view_header(direction)),
+                    self.show_weekdays.then(|| tr![
+                        attrs! { At::from("role") => "row" },
+                        self.show_week_numbers.then(|| th![
+                            C!["week-number"],
+                            attrs! { At::from("role") => "columnheader" },
+                            span![]
+                        ]),
+                        grid.weekdays().into_iter().map(|weekday| th![
+                            attrs! {
+                                At::from("role") => "columnheader",
+                                At::AriaLabel => format::weekday(weekday, Width::Long, locale),
+                            },
+                            format::weekday(weekday, Width::Narrow, locale)
+                        ])
+                    ])
                 ]
-            })
+            }),
+            grid.weeks()
+                .into_iter()
+                .map(|week| self.view_week(week, tabbable))
         ]
     }
-}
-
-// UpdateEl
 
-impl<Ms: 'static> UpdateEl<Ms> for MonthView<Ms> {
-    fn update_el(self, parent: &mut El<Ms>) {
-        parent.add_child(self.into_node());
+    // View helpers
+
+    fn view_week(&self, week: GridWeek, tabbable: NaiveDate) -> Node<Ms> {
+        tr![
+            attrs! { At::from("role") => "row" },
+            self.show_week_numbers.then(|| td![
+                C!["week-number"],
+                attrs! { At::from("role") => "rowheader" },
+                div![span![format::number(
+                    week.week.number().into(),
+                    self.grid.locale().as_str()
+                )]]
+            ]),
+            C![
+                helpers::selection_class(week.selection),
+                IF!(week.contains_today => "today"),
+            ],
+            IF!(week.contains_today => attrs! { At::AriaCurrent => "date" }),
+            week.days
+                .into_iter()
+                .map(|day| self.view_day(day, tabbable))
+        ]
     }
-}
 
-// Helpers
+    fn view_day(&self, day: GridDay, tabbable: NaiveDate) -> Node<Ms> {
+        let GridDay { date, state, .. } = day;
+        let locale = self.grid.locale().as_str();
+        let calendar = self.grid.calendar().identifier();
+        let secondary_calendar = self.secondary_calendar();
+
+        let content = match &self.day_renderer {
+            Some(renderer) => vec![renderer(date, state)],
+            None => vec![
+                plain![format::number(day.day.into(), locale)],
+                secondary_calendar.map_or(empty(), |secondary_calendar| {
+                    small![
+                        C!["secondary"],
+                        format::day_annotation(date, secondary_calendar, locale)
+                    ]
+                }),
+            ],
+        };
 
-mod helpers {
-    use chrono::{Datelike, Duration, NaiveDate, Weekday};
+        if !state.in_month {
+            return td![
+                attrs! { At::from("role") => "gridcell" },
+                div![attrs! { At::AriaHidden => "true" }, content]
+            ];
+        }
 
-    pub fn days_since_earliest_weekday(weekday: Weekday) -> u32 {
-        match weekday {
-            Weekday::Sat => 0,
-            Weekday::Sun => 1,
-            Weekday::Mon => 2,
-            Weekday::Tue => 3,
-            Weekday::Wed => 4,
-            Weekday::Thu => 5,
-            Weekday::Fri => 6,
+        let label = match secondary_calendar {
+            Some(secondary_calendar) => format!(
+                "{} ({})",
+                format::date(date, calendar, locale),
+                format::date(date, secondary_calendar, locale)
+            ),
+            None => format::date(date, calendar, locale),
+        };
+
+        if state.disabled {
+            td![
+                attrs! {
+                    At::from("role") => "gridcell",
+                    At::AriaSelected => state.is_selected(),
+                    At::AriaDisabled => "true",
+                },
+                IF!(state.today => attrs! { At::AriaCurrent => "date" }),
+                div![button![
+                    C!["disabled", IF!(state.today => "today")],
+                    attrs! {
+                        At::Disabled => AtValue::None,
+                        At::AriaDisabled => "true",
+                        At::AriaLabel => label,
+                        At::TabIndex => -1,
+                    },
+                    content
+                ]]
+            ]
+        } else {
+            let on_click = self.on_click.clone();
+
+            td![
+                attrs! {
+                    At::from("role") => "gridcell",
+                    At::AriaSelected => state.is_selected(),
+                },
+                IF!(state.today => attrs! { At::AriaCurrent => "date" }),
+                div![button![
+                    C![
+                        helpers::selection_class(state.selection),
+                        IF!(state.today => "today"),
+                    ],
+                    attrs! {
+                        At::AriaLabel => label,
+                        At::TabIndex => if date == tabbable { 0 } else { -1 },
+                    },
+                    on_click.map(|on_click| ev(Ev::Click, move |_| on_click(date))),
+                    content
+                ]]
+            ]
         }
     }
 
-    pub fn days_since_start_of_week(date: NaiveDate, first_weekday: Weekday) -> i32 {
-        days_since_earliest_weekday(date.weekday()) as i32
-            - days_since_earliest_weekday(first_weekday) as i32
+    fn secondary_calendar(&self) -> Option<&str> {
+        self.secondary_calendar
+            .as_deref()
+            .filter(|calendar| format::supports_calendar(calendar))
     }
 
-    pub fn start_of_week(date: NaiveDate, first_weekday: Weekday) -> NaiveDate {
-        let offset = days_since_start_of_week(date, first_weekday);
+    // Enter and Space are left to the browser, which turns them into clicks on the focused button.
+    fn keyboard_handler(
+        &self,
+        focused: NaiveDate,
+        first_weekday: Weekday,
+        direction: Direction,
+        on_focus_change: Rc<dyn Fn(NaiveDate) -> Ms>,
+    ) -> EventHandler<Ms> {
+        let grid = self.grid.clone();
+
+        keyboard_ev(Ev::KeyDown, move |event| {
+            let calendar = grid.calendar();
+            let key = direction.logical_key(event.key());
+            let mut date =
+                helpers::navigate(focused, &key, event.shift_key(), first_weekday, calendar)?;
+            event.prevent_default();
+
+            // Skip past disabled days, as they can't receive focus
+            for _ in 0..MAX_SKIPPED_DAYS {
+                if !grid.is_disabled(date) {
+                    return Some(on_focus_change(date));
+                }
+
+                match helpers::navigate(date, &key, event.shift_key(), first_weekday, calendar) {
+                    Some(next) if next != date => date = next,
+                    _ => break,
+                }
+            }
 
-        date - Duration::days(offset as i64)
+            None
+        })
     }
 
-    pub fn week_number(date: NaiveDate, first_weekday: Weekday) -> u32 {
-        let offset = first_weekday.num_days_from_monday();
-        let adjusted_date = date + Duration::days(offset as i64);
+    fn view_header(&self, direction: Direction) -> Node<Ms> {
+        let grid = &self.grid;
+        let columns = if self.show_week_numbers { 8 } else { 7 };
+        let first_of_month = grid.first_of_month();
+        // Without a handler there's nowhere to navigate, so the buttons are disabled
+        let previous_month = self.on_navigate.as_ref().and(grid.previous_month());
+        let next_month = self.on_navigate.as_ref().and(grid.next_month());
+        let title = format::month_and_year(
+            first_of_month,
+            grid.calendar().identifier(),
+            grid.locale().as_str(),
+        );
+        let (previous_label, next_label) = match &self.navigation_labels {
+            Some((previous, next)) => (previous.as_str(), next.as_str()),
+            None => ("Previous month", "Next month"),
+        };
 
-        adjusted_date.iso_week().week()
+        tr![
+            C!["header"],
+            attrs! { At::from("role") => "presentation" },
+            th![button![
+                C!["previous"],
+                attrs! { At::AriaLabel => previous_label },
+                IF!(previous_month.is_none() => attrs! { At::Disabled => AtValue::None }),
+                self.on_navigate.clone().zip(previous_month).map(
+                    |(on_navigate, (year, month))| ev(Ev::Click, move |_| on_navigate(year, month))
+                ),
+                direction.previous_arrow()
+            ]],
+            th![
+                C!["title"],
+                attrs! {
+                    At::ColSpan => columns - 2,
+                    At::AriaLive => "polite",
+                },
+                match self.on_title_click.clone() {
+                    Some(on_title_click) =>
+                        button![ev(Ev::Click, move |_| on_title_click()), title],
+                    None => span![title],
+                },
+                self.secondary_calendar().map(|calendar| {
+                    small![
+                        C!["secondary"],
+                        format::month_range(
+                            first_of_month,
+                            grid.last_of_month(),
+                            calendar,
+                            grid.locale().as_str()
+                        )
+                    ]
+                })
+            ],
+            th![button![
+                C!["next"],
+                attrs! { At::AriaLabel => next_label },
+                IF!(next_month.is_none() => attrs! { At::Disabled => AtValue::None }),
+                self.on_navigate
+                    .clone()
+                    .zip(next_month)
+                    .map(
+                        |(on_navigate, (year, month))| ev(Ev::Click, move |_| on_navigate(
+                            year, month
+                        ))
+                    ),
+                direction.next_arrow()
+            ]],
+        ]
     }
 
-    pub fn format_weekday(day: Weekday, locale: &str) -> String {
-        use crate::util::intl;
-        use js_sys::*;
-        use wasm_bindgen::prelude::*;
+    /// Renders the view to static HTML, e.g. to prerender it on the server. See `crate::html`.
+    pub fn into_html(self) -> String {
+        html::render(&self.into_node())
+    }
+}
 
-        let opts = Object::new();
-        Reflect::set(&opts, &JsValue::from("weekday"), &JsValue::from("narrow")).unwrap();
+// UpdateEl
+
+impl<Ms: 'static> UpdateEl<Ms> for MonthView<Ms> {
+    fn update_el(self, parent: &mut El<Ms>) {
+        parent.add_child(self.into_node());
+    }
+}
 
-        let formatter = intl::DateTimeFormat::new(&Array::of1(&JsValue::from(locale)), &opts);
+// Helpers
 
-        let datetime = NaiveDate::from_isoywd(1970, 1, day).and_hms(12, 0, 0);
-        let js_date = Date::new(&JsValue::from(datetime.timestamp_millis() as f64));
+mod helpers {
+    use super::Intersection;
+    use crate::calendar::Calendar;
+    use chrono::{Datelike, Duration, NaiveDate, Weekday};
 
-        formatter.format(&js_date).as_string().unwrap()
+    /// The date a key moves the focus to, or `None` if the key doesn't navigate or would move
+    /// past the range of dates.
+    pub fn navigate(
+        date: NaiveDate,
+        key: &str,
+        shift: bool,
+        first_weekday: Weekday,
+        calendar: Calendar,
+    ) -> Option<NaiveDate> {
+        let days_since_start_of_week =
+            (7 + date.weekday().num_days_from_monday() - first_weekday.num_days_from_monday()) % 7;
+
+        match key {
+            "ArrowLeft" => date.checked_sub_signed(Duration::days(1)),
+            "ArrowRight" => date.checked_add_signed(Duration::days(1)),
+            "ArrowUp" => date.checked_sub_signed(Duration::weeks(1)),
+            "ArrowDown" => date.checked_add_signed(Duration::weeks(1)),
+            "PageUp" if shift => Some(calendar.add_months(date, -12)),
+            "PageDown" if shift => Some(calendar.add_months(date, 12)),
+            "PageUp" => Some(calendar.add_months(date, -1)),
+            "PageDown" => Some(calendar.add_months(date, 1)),
+            "Home" => date.checked_sub_signed(Duration::days(days_since_start_of_week.into())),
+            "End" => date.checked_add_signed(Duration::days((6 - days_since_start_of_week).into())),
+            _ => None,
+        }
+    }
+
+    pub fn selection_class(intersection: Option<Intersection>) -> &'static str {
+        match intersection {
+            Some(Intersection::All) => "selected",
+            Some(Intersection::Start) => "selection-start",
+            Some(Intersection::End) => "selection-end",
+            Some(Intersection::Inside) => "in-selection",
+            None => "",
+        }
     }
 }
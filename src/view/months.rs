@@ -1,21 +1,90 @@
+use chrono::NaiveDate;
 use seed::{prelude::*, *};
 
 use std::rc::Rc;
 
+use crate::calendar::Calendar;
+use crate::html;
+use crate::locale::Locale;
+use crate::util::direction;
+use crate::util::format::{self, Width};
+use crate::Error;
+
 pub struct MonthsView<Ms> {
+    year: Option<i32>,
     selected: Option<u32>,
+    focused: Option<u32>,
+    today: Option<NaiveDate>,
     on_click: Option<Rc<dyn Fn(u32) -> Ms>>,
-    locale: String,
+    on_focus_change: Option<Rc<dyn Fn(u32) -> Ms>>,
+    locale: Locale,
+    style: MonthStyle,
+    form: MonthForm,
+    layout: MonthsLayout,
+    calendar: Calendar,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MonthStyle {
+    Narrow,
+    Short,
+    Long,
+}
+
+impl MonthStyle {
+    fn width(self) -> Width {
+        match self {
+            MonthStyle::Narrow => Width::Narrow,
+            MonthStyle::Short => Width::Short,
+            MonthStyle::Long => Width::Long,
+        }
+    }
+}
+
+/// Many languages inflect month names differently when they stand on their own, as in a list of
+/// months, than when they're part of a date, e.g. Russian "январь" vs. "1 января".
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MonthForm {
+    Standalone,
+    Format,
+}
+
+/// The arrangement of the months, in columns by rows. Calendars with leap months, like the Hebrew,
+/// add a partial row in leap years.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MonthsLayout {
+    ThreeByFour,
+    FourByThree,
+    TwoBySix,
+}
+
+impl MonthsLayout {
+    fn columns(self) -> u32 {
+        match self {
+            MonthsLayout::ThreeByFour => 3,
+            MonthsLayout::FourByThree => 4,
+            MonthsLayout::TwoBySix => 2,
+        }
+    }
 }
 
 impl<Ms: 'static> MonthsView<Ms> {
     // Constructor
 
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
         MonthsView {
+            year: None,
             selected: None,
+            focused: None,
+            today: None,
             on_click: None,
-            locale: "en-US".into(),
+            on_focus_change: None,
+            locale: Locale::default(),
+            style: MonthStyle::Short,
+            form: MonthForm::Standalone,
+            layout: MonthsLayout::ThreeByFour,
+            calendar: Calendar::Gregorian,
         }
     }
 
@@ -26,27 +95,178 @@ impl<Ms: 'static> MonthsView<Ms> {
         self
     }
 
+    /// The month that can be tabbed to, and that arrow keys move from. By default it's the selected
+    /// month, else the current one, else the first.
+    pub fn with_focused(mut self, month: u32) -> Self {
+        self.focused = Some(month);
+        self
+    }
+
+    pub fn with_year(mut self, year: i32) -> Self {
+        self.year = Some(year);
+        self
+    }
+
+    pub fn with_today(mut self, date: NaiveDate) -> Self {
+        self.today = Some(date);
+        self
+    }
+
+    pub fn with_locale(mut self, locale: impl Into<Locale>) -> Self {
+        self.locale = locale.into();
+        self
+    }
+
+    /// Like `with_locale`, but fails if the locale isn't a well-formed language tag.
+    pub fn try_with_locale(self, locale: impl AsRef<str>) -> Result<Self, Error> {
+        Ok(self.with_locale(Locale::new(locale.as_ref())?))
+    }
+
+    pub fn with_style(mut self, style: MonthStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_form(mut self, form: MonthForm) -> Self {
+        self.form = form;
+        self
+    }
+
+    pub fn with_layout(mut self, layout: MonthsLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Lists the months of a year in the given calendar. Years and months passed to `with_year`,
+    /// `with_selected` and `on_click` are then in that calendar too.
+    pub fn with_calendar(mut self, calendar: Calendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
     pub fn on_click(mut self, handler: impl FnOnce(u32) -> Ms + Clone + 'static) -> Self {
         self.on_click = Some(Rc::new(move |year| handler.clone()(year)));
         self
     }
 
+    /// Called with the month to move the focus to when an arrow key, Home or End is pressed. Left
+    /// and right move by a month, up and down by a row of the layout.
+    pub fn on_focus_change(mut self, handler: impl FnOnce(u32) -> Ms + Clone + 'static) -> Self {
+        self.on_focus_change = Some(Rc::new(move |month| handler.clone()(month)));
+        self
+    }
+
     // Consumers
 
     pub fn into_node(self) -> Node<Ms> {
+        // Without a year, month names are taken from an arbitrary common year
+        let year = self.year.unwrap_or_else(|| {
+            self.calendar
+                .from_date(NaiveDate::from_ymd(2001, 1, 1))
+                .year
+        });
+        let today = self.today.map(|today| self.calendar.from_date(today));
+        let standalone = self.form == MonthForm::Standalone;
+        let calendar = self.calendar.identifier();
+        let direction = direction::for_locale(self.locale.as_str());
+
+        // Months outside the range of supported dates are left out
+        let months: Vec<(u32, NaiveDate)> = (1..=self.calendar.months_in_year(year))
+            .filter_map(|month| Some((month, self.calendar.first_of_month(year, month)?)))
+            .collect();
+        let is_current = |month: u32| match today {
+            Some(today) => {
+                today.month == month && (self.year.is_none() || self.year == Some(today.year))
+            }
+            None => false,
+        };
+        let is_listed = |month: &u32| months.iter().any(|&(listed, _)| listed == *month);
+        let tabbable = self
+            .focused
+            .filter(is_listed)
+            .or_else(|| self.selected.filter(is_listed))
+            .or_else(|| {
+                months
+                    .iter()
+                    .map(|&(month, _)| month)
+                    .find(|&month| is_current(month))
+            })
+            .or_else(|| months.first().map(|&(month, _)| month));
+
         ul![
-            C!["seed-calendar-months-view"],
-            (1..=12).map(|month| {
+            C![
+                "seed-calendar-months-view",
+                format!("columns-{}", self.layout.columns()),
+            ],
+            attrs! {
+                At::from("role") => "listbox",
+                At::Dir => direction.as_str(),
+            },
+            self.on_focus_change
+                .clone()
+                .zip(tabbable)
+                .zip(months.first().zip(months.last()))
+                .map(|((on_focus_change, focused), (&(first, _), &(last, _)))| {
+                    let columns = self.layout.columns();
+
+                    keyboard_ev(Ev::KeyDown, move |event| {
+                        let month = match direction.logical_key(event.key()).as_str() {
+                            "ArrowLeft" => focused.checked_sub(1)?,
+                            "ArrowRight" => focused + 1,
+                            "ArrowUp" => focused.checked_sub(columns)?,
+                            "ArrowDown" => focused + columns,
+                            "Home" => first,
+                            "End" => last,
+                            _ => return None,
+                        };
+                        event.prevent_default();
+
+                        (first..=last).contains(&month).then(|| on_focus_change(month))
+                    })
+                }),
+            months.iter().map(|&(month, first_of_month)| {
+                let is_current = is_current(month);
+
                 li![
-                    C![IF!(self.selected == Some(month) => "selected"),],
-                    self.on_click
-                        .clone()
-                        .map(|on_click| ev(Ev::Click, move |_| on_click(month))),
-                    helpers::format_month(month, &self.locale),
+                    C![
+                        IF!(self.selected == Some(month) => "selected"),
+                        IF!(is_current => "today"),
+                    ],
+                    attrs! {
+                        At::from("role") => "option",
+                        At::AriaSelected => self.selected == Some(month),
+                        At::AriaLabel => format::month(first_of_month, Width::Long, standalone, calendar, self.locale.as_str()),
+                        At::TabIndex => if tabbable == Some(month) { 0 } else { -1 },
+                    },
+                    IF!(is_current => attrs! { At::AriaCurrent => "date" }),
+                    self.on_click.clone().map(|on_click| {
+                        let on_key_down = on_click.clone();
+                        vec![
+                            ev(Ev::Click, move |_| on_click(month)),
+                            keyboard_ev(Ev::KeyDown, move |event| {
+                                IF!(event.key() == "Enter" || event.key() == " " => {
+                                    event.prevent_default();
+                                    on_key_down(month)
+                                })
+                            }),
+                        ]
+                    }),
+                    format::month(
+                        first_of_month,
+                        self.style.width(),
+                        standalone,
+                        calendar,
+                        self.locale.as_str()
+                    ),
                 ]
             })
         ]
     }
+
+    /// Renders the view to static HTML, e.g. to prerender it on the server. See `crate::html`.
+    pub fn into_html(self) -> String {
+        html::render(&self.into_node())
+    }
 }
 
 // UpdateEl
@@ -56,25 +276,3 @@ impl<Ms: 'static> UpdateEl<Ms> for MonthsView<Ms> {
         parent.add_child(self.into_node());
     }
 }
-
-// Helpers
-
-mod helpers {
-    use chrono::NaiveDate;
-
-    pub fn format_month(month: u32, locale: &str) -> String {
-        use crate::util::intl;
-        use js_sys::*;
-        use wasm_bindgen::prelude::*;
-
-        let opts = Object::new();
-        Reflect::set(&opts, &JsValue::from("month"), &JsValue::from("short")).unwrap();
-
-        let formatter = intl::DateTimeFormat::new(&Array::of1(&JsValue::from(locale)), &opts);
-
-        let datetime = NaiveDate::from_ymd(1970, month, 1).and_hms(12, 0, 0);
-        let js_date = Date::new(&JsValue::from(datetime.timestamp_millis() as f64));
-
-        formatter.format(&js_date).as_string().unwrap()
-    }
-}
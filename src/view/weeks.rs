@@ -1,23 +1,35 @@
-use chrono::{Datelike, IsoWeek, NaiveDate, Weekday};
+use chrono::{NaiveDate, Weekday};
 use seed::{prelude::*, *};
 use std::rc::Rc;
 
+use crate::core::{MonthGrid, Selection as DateSelection, Week, WeekNumbering};
+use crate::html;
+use crate::locale::Locale;
+use crate::util::direction;
 use crate::view::month;
+use crate::Error;
 
+/// A month for picking weeks. Weeks are numbered, selected and reported to `on_click` by the
+/// same rule, from the week numbering scheme, or `WeekRule::iso_aligned` with the first weekday
+/// otherwise, so a selected week is always highlighted on the row it's numbered by.
 pub struct WeeksView<Ms> {
     year: i32,
     month: u32,
     selection: Selection,
-    on_click: Option<Rc<dyn Fn(IsoWeek) -> Ms>>,
-    first_weekday: Weekday,
+    min_date: Option<NaiveDate>,
+    max_date: Option<NaiveDate>,
+    today: Option<NaiveDate>,
+    on_click: Option<Rc<dyn Fn(Week) -> Ms>>,
+    first_weekday: Option<Weekday>,
+    week_numbering: Option<WeekNumbering>,
     show_weekdays: bool,
-    locale: String,
+    locale: Locale,
 }
 
 enum Selection {
     None,
-    Single(IsoWeek),
-    Range(IsoWeek, IsoWeek),
+    Single(Week),
+    Range(Week, Week),
 }
 
 impl<Ms: 'static> WeeksView<Ms> {
@@ -28,21 +40,33 @@ impl<Ms: 'static> WeeksView<Ms> {
             year,
             month,
             selection: Selection::None,
+            min_date: None,
+            max_date: None,
+            today: None,
             on_click: None,
-            first_weekday: Weekday::Mon,
+            first_weekday: None,
+            week_numbering: None,
             show_weekdays: false,
-            locale: String::from("en-US"),
+            locale: Locale::default(),
         }
     }
 
+    /// Like `new`, but fails if the month doesn't exist.
+    pub fn try_new(year: i32, month: u32) -> Result<Self, Error> {
+        MonthGrid::try_new(year, month)?;
+
+        Ok(WeeksView::new(year, month))
+    }
+
     // Builder functions
 
-    pub fn with_selected(mut self, date: IsoWeek) -> Self {
+    pub fn with_selected(mut self, date: Week) -> Self {
         self.selection = Selection::Single(date);
         self
     }
 
-    pub fn with_selection(mut self, start: IsoWeek, end: IsoWeek) -> Self {
+    #[allow(clippy::comparison_chain)]
+    pub fn with_selection(mut self, start: Week, end: Week) -> Self {
         self.selection = if start == end {
             Selection::Single(start)
         } else if start > end {
@@ -53,7 +77,7 @@ impl<Ms: 'static> WeeksView<Ms> {
         self
     }
 
-    pub fn maybe_with_selection(self, start: Option<IsoWeek>, end: Option<IsoWeek>) -> Self {
+    pub fn maybe_with_selection(self, start: Option<Week>, end: Option<Week>) -> Self {
         match (start, end) {
             (None, None) => self,
             (Some(date), None) | (None, Some(date)) => self.with_selected(date),
@@ -61,22 +85,56 @@ impl<Ms: 'static> WeeksView<Ms> {
         }
     }
 
+    pub fn with_min_date(mut self, date: NaiveDate) -> Self {
+        self.min_date = Some(date);
+        self
+    }
+
+    pub fn with_max_date(mut self, date: NaiveDate) -> Self {
+        self.max_date = Some(date);
+        self
+    }
+
+    /// Sets both the minimum and maximum date, failing if the minimum is after the maximum.
+    pub fn try_with_date_range(self, min: NaiveDate, max: NaiveDate) -> Result<Self, Error> {
+        Error::check_date_range(min, max)?;
+
+        Ok(self.with_min_date(min).with_max_date(max))
+    }
+
+    pub fn with_today(mut self, date: NaiveDate) -> Self {
+        self.today = Some(date);
+        self
+    }
+
     pub fn with_first_weekday(mut self, weekday: Weekday) -> Self {
-        self.first_weekday = weekday;
+        self.first_weekday = Some(weekday);
         self
     }
 
-    pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
+    /// Numbers the weeks by the given scheme, which decides the first weekday too. See
+    /// `WeekNumbering`.
+    pub fn with_week_numbering(mut self, numbering: WeekNumbering) -> Self {
+        self.week_numbering = Some(numbering);
+        self
+    }
+
+    pub fn with_locale(mut self, locale: impl Into<Locale>) -> Self {
         self.locale = locale.into();
         self
     }
 
+    /// Like `with_locale`, but fails if the locale isn't a well-formed language tag.
+    pub fn try_with_locale(self, locale: impl AsRef<str>) -> Result<Self, Error> {
+        Ok(self.with_locale(Locale::new(locale.as_ref())?))
+    }
+
     pub fn show_weekdays(mut self) -> Self {
         self.show_weekdays = true;
         self
     }
 
-    pub fn on_click(mut self, handler: impl FnOnce(IsoWeek) -> Ms + Clone + 'static) -> Self {
+    pub fn on_click(mut self, handler: impl FnOnce(Week) -> Ms + Clone + 'static) -> Self {
         self.on_click = Some(Rc::new(move |date| handler.clone()(date)));
         self
     }
@@ -84,32 +142,60 @@ impl<Ms: 'static> WeeksView<Ms> {
     // Consumers
 
     pub fn into_node(self) -> Node<Ms> {
+        let mut grid = MonthGrid::new(self.year, self.month)
+            .with_selection(match self.selection {
+                Selection::None => DateSelection::None,
+                Selection::Single(week) => DateSelection::Single(week.first_day()),
+                Selection::Range(start, end) => {
+                    DateSelection::Range(start.first_day(), end.first_day())
+                }
+            })
+            .with_locale(self.locale.clone());
+        if let Some(weekday) = self.first_weekday {
+            grid = grid.with_first_weekday(weekday);
+        }
+        if let Some(numbering) = self.week_numbering {
+            grid = grid.with_week_numbering(numbering);
+        }
+        if let Some(date) = self.min_date {
+            grid = grid.with_min_date(date);
+        }
+        if let Some(date) = self.max_date {
+            grid = grid.with_max_date(date);
+        }
+        if let Some(date) = self.today {
+            grid = grid.with_today(date);
+        }
+
+        let rule = grid.week_rule();
         let month_model = month::MonthView {
-            year: self.year,
-            month: self.month,
-            selection: match self.selection {
-                Selection::None => month::Selection::None,
-                Selection::Single(week) => month::Selection::Single(NaiveDate::from_isoywd(
-                    week.year(),
-                    week.week(),
-                    self.first_weekday,
-                )),
-                Selection::Range(start, end) => month::Selection::Range(
-                    NaiveDate::from_isoywd(start.year(), start.week(), self.first_weekday),
-                    NaiveDate::from_isoywd(end.year(), end.week(), self.first_weekday),
-                ),
-            },
+            grid,
+            day_renderer: None,
+            focused: None,
             on_click: match self.on_click {
-                Some(handler) => Some(Rc::new(move |date: NaiveDate| handler(date.iso_week()))),
+                Some(handler) => Some(Rc::new(move |date: NaiveDate| handler(rule.week(date)))),
                 None => None,
             },
-            first_weekday: self.first_weekday,
+            on_focus_change: None,
+            on_navigate: None,
+            on_title_click: None,
             show_week_numbers: true,
             show_weekdays: self.show_weekdays,
-            locale: self.locale,
+            show_header: false,
+            secondary_calendar: None,
+            navigation_labels: None,
         };
 
-        div![C!["seed-calendar-weeks-view"], month_model]
+        div![
+            C!["seed-calendar-weeks-view"],
+            attrs! { At::Dir => direction::for_locale(self.locale.as_str()).as_str() },
+            month_model
+        ]
+    }
+
+    /// Renders the view to static HTML, e.g. to prerender it on the server. See `crate::html`.
+    pub fn into_html(self) -> String {
+        html::render(&self.into_node())
     }
 }
 
@@ -1,14 +1,26 @@
+use chrono::NaiveDate;
 use seed::{prelude::*, *};
 
 use std::rc::Rc;
 
+use crate::calendar::Calendar;
+use crate::html;
+use crate::locale::Locale;
+use crate::util::{direction, format};
+use crate::Error;
+
 pub struct YearsView<Ms> {
     from: i32,
     to: i32,
     selected: Option<i32>,
+    focused: Option<i32>,
     min: Option<i32>,
     max: Option<i32>,
+    today: Option<NaiveDate>,
     on_click: Option<Rc<dyn Fn(i32) -> Ms>>,
+    on_focus_change: Option<Rc<dyn Fn(i32) -> Ms>>,
+    locale: Locale,
+    calendar: Calendar,
 }
 
 impl<Ms: 'static> YearsView<Ms> {
@@ -19,20 +31,39 @@ impl<Ms: 'static> YearsView<Ms> {
             from,
             to,
             selected: None,
+            focused: None,
             min: None,
             max: None,
+            today: None,
             on_click: None,
+            on_focus_change: None,
+            locale: Locale::default(),
+            calendar: Calendar::Gregorian,
         }
     }
 
+    /// Like `new`, but fails if the range is inverted, rather than rendering no years.
+    pub fn try_new(from: i32, to: i32) -> Result<Self, Error> {
+        if from > to {
+            return Err(Error::InvertedYearRange { from, to });
+        }
+
+        Ok(YearsView::new(from, to))
+    }
+
     pub fn decade_from(start: i32) -> Self {
         YearsView {
             from: start - 1,
             to: start + 10,
             selected: None,
+            focused: None,
             min: Some(start),
             max: Some(start + 9),
+            today: None,
             on_click: None,
+            on_focus_change: None,
+            locale: Locale::default(),
+            calendar: Calendar::Gregorian,
         }
     }
 
@@ -43,31 +74,128 @@ impl<Ms: 'static> YearsView<Ms> {
         self
     }
 
+    /// The year that can be tabbed to, and that arrow keys move from. By default it's the selected
+    /// year, else the current one, else the first that isn't disabled.
+    pub fn with_focused(mut self, year: i32) -> Self {
+        self.focused = Some(year);
+        self
+    }
+
+    pub fn with_today(mut self, date: NaiveDate) -> Self {
+        self.today = Some(date);
+        self
+    }
+
+    pub fn with_locale(mut self, locale: impl Into<Locale>) -> Self {
+        self.locale = locale.into();
+        self
+    }
+
+    /// Like `with_locale`, but fails if the locale isn't a well-formed language tag.
+    pub fn try_with_locale(self, locale: impl AsRef<str>) -> Result<Self, Error> {
+        Ok(self.with_locale(Locale::new(locale.as_ref())?))
+    }
+
+    /// Numbers and labels years in the given calendar, e.g. by era in the Japanese calendar.
+    pub fn with_calendar(mut self, calendar: Calendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
     pub fn on_click(mut self, handler: impl FnOnce(i32) -> Ms + Clone + 'static) -> Self {
         self.on_click = Some(Rc::new(move |year| handler.clone()(year)));
         self
     }
 
+    /// Called with the year to move the focus to when an arrow key, Home or End is pressed. Left
+    /// and up move to the previous year, right and down to the next.
+    pub fn on_focus_change(mut self, handler: impl FnOnce(i32) -> Ms + Clone + 'static) -> Self {
+        self.on_focus_change = Some(Rc::new(move |year| handler.clone()(year)));
+        self
+    }
+
     // Consumers
 
     pub fn into_node(self) -> Node<Ms> {
+        let (from, to) = (self.from, self.to);
+        let direction = direction::for_locale(self.locale.as_str());
+        let is_disabled = |year: i32| {
+            (self.min.is_some() && self.min > Some(year))
+                || (self.max.is_some() && self.max < Some(year))
+        };
+        let is_current = |year: i32| match self.today {
+            Some(today) => self.calendar.from_date(today).year == year,
+            None => false,
+        };
+        let is_listed = |year: &i32| (from..=to).contains(year);
+        let tabbable = self
+            .focused
+            .filter(is_listed)
+            .or_else(|| self.selected.filter(is_listed))
+            .or_else(|| (from..=to).find(|&year| is_current(year)))
+            .or_else(|| (from..=to).find(|&year| !is_disabled(year)))
+            .unwrap_or(from);
+
         ul![
             C!["seed-calendar-years-view"],
-            (self.from..=self.to).map(|year| {
+            attrs! {
+                At::from("role") => "listbox",
+                At::Dir => direction.as_str(),
+            },
+            self.on_focus_change.clone().map(|on_focus_change| {
+                keyboard_ev(Ev::KeyDown, move |event| {
+                    let year = match direction.logical_key(event.key()).as_str() {
+                        "ArrowLeft" | "ArrowUp" => tabbable - 1,
+                        "ArrowRight" | "ArrowDown" => tabbable + 1,
+                        "Home" => from,
+                        "End" => to,
+                        _ => return None,
+                    };
+                    event.prevent_default();
+
+                    (from..=to).contains(&year).then(|| on_focus_change(year))
+                })
+            }),
+            (from..=to).map(|year| {
+                let disabled = is_disabled(year);
+                let is_current = is_current(year);
+
                 li![
                     C![
                         IF!(self.selected == Some(year) => "selected"),
-                        IF!(self.min.is_some() && self.min > Some(year) => "disabled"),
-                        IF!(self.max.is_some() && self.max < Some(year) => "disabled"),
+                        IF!(disabled => "disabled"),
+                        IF!(is_current => "today"),
                     ],
-                    self.on_click
-                        .clone()
-                        .map(|on_click| ev(Ev::Click, move |_| on_click(year))),
-                    year
+                    attrs! {
+                        At::from("role") => "option",
+                        At::AriaSelected => self.selected == Some(year),
+                        At::TabIndex => if year == tabbable { 0 } else { -1 },
+                    },
+                    IF!(disabled => attrs! { At::AriaDisabled => "true" }),
+                    IF!(is_current => attrs! { At::AriaCurrent => "date" }),
+                    // Disabled years can't be picked, so they don't handle clicks or keys
+                    self.on_click.clone().filter(|_| !disabled).map(|on_click| {
+                        let on_key_down = on_click.clone();
+                        vec![
+                            ev(Ev::Click, move |_| on_click(year)),
+                            keyboard_ev(Ev::KeyDown, move |event| {
+                                IF!(event.key() == "Enter" || event.key() == " " => {
+                                    event.prevent_default();
+                                    on_key_down(year)
+                                })
+                            }),
+                        ]
+                    }),
+                    format::year(year, self.calendar, self.locale.as_str())
                 ]
             })
         ]
     }
+
+    /// Renders the view to static HTML, e.g. to prerender it on the server. See `crate::html`.
+    pub fn into_html(self) -> String {
+        html::render(&self.into_node())
+    }
 }
 
 // UpdateEl
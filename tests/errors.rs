@@ -0,0 +1,90 @@
+use chrono::NaiveDate;
+use seed_calendar::calendar::Calendar;
+use seed_calendar::core::MonthGrid;
+use seed_calendar::view::{MonthView, WeeksView, YearsView};
+use seed_calendar::Error;
+
+#[test]
+fn invalid_month() {
+    assert_eq!(
+        MonthView::<()>::try_new(2021, 13).err(),
+        Some(Error::InvalidMonth {
+            year: 2021,
+            month: 13
+        })
+    );
+    assert!(MonthView::<()>::try_new(2021, 12).is_ok());
+
+    // Only leap years have a 13th month
+    assert!(MonthGrid::new(5781, 13)
+        .try_with_calendar(Calendar::Hebrew)
+        .is_err());
+    assert!(MonthGrid::new(5782, 13)
+        .try_with_calendar(Calendar::Hebrew)
+        .is_ok());
+}
+
+#[test]
+fn invalid_month_renders_nothing() {
+    assert_eq!(MonthView::<()>::new(2021, 13).into_html(), "");
+}
+
+#[test]
+fn months_at_the_ends_of_the_range_of_dates() {
+    // Their grids would run past the first or last date
+    assert!(MonthView::<()>::try_new(-262144, 1).is_err());
+    assert!(MonthView::<()>::try_new(262143, 12).is_err());
+    assert_eq!(MonthView::<()>::new(-262144, 1).into_html(), "");
+
+    // The weeks of the last years can't be counted from the next year
+    for &(year, month) in &[(-262144, 2), (262143, 5), (262143, 11)] {
+        assert!(MonthView::<()>::try_new(year, month).is_ok());
+        assert!(!MonthView::<()>::new(year, month)
+            .show_week_numbers()
+            .into_html()
+            .is_empty());
+        assert!(!WeeksView::<()>::new(year, month).into_html().is_empty());
+    }
+}
+
+#[test]
+fn inverted_ranges() {
+    assert_eq!(
+        YearsView::<()>::try_new(2030, 2020).err(),
+        Some(Error::InvertedYearRange {
+            from: 2030,
+            to: 2020
+        })
+    );
+
+    let (min, max) = (
+        NaiveDate::from_ymd(2021, 4, 30),
+        NaiveDate::from_ymd(2021, 4, 1),
+    );
+    assert_eq!(
+        MonthView::<()>::new(2021, 4)
+            .try_with_date_range(min, max)
+            .err(),
+        Some(Error::InvertedDateRange { min, max })
+    );
+}
+
+#[test]
+fn unsupported_locale() {
+    for locale in &["en-US", "de", "zh-Hant-TW", "th-TH-u-nu-thai", "es-419"] {
+        assert!(
+            MonthView::<()>::new(2021, 4)
+                .try_with_locale(*locale)
+                .is_ok(),
+            "{}",
+            locale
+        );
+    }
+
+    for locale in &["", "e", "en_US", "en--US", "english-united-states!"] {
+        assert_eq!(
+            MonthView::<()>::new(2021, 4).try_with_locale(*locale).err(),
+            Some(Error::UnsupportedLocale(String::from(*locale)))
+        );
+    }
+}
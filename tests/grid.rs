@@ -0,0 +1,471 @@
+// proptest is only a dependency on native targets
+#![cfg(not(target_arch = "wasm32"))]
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use proptest::prelude::*;
+use seed_calendar::calendar::Calendar;
+use seed_calendar::core::{
+    start_of_week, GridWeek, Intersection, MonthGrid, Selection, WeekNumbering, WeekRule,
+};
+use seed_calendar::view::MonthView;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd(year, month, day)
+}
+
+fn first_days(grid: &MonthGrid) -> Vec<NaiveDate> {
+    grid.weeks().iter().map(|week| week.days[0].date).collect()
+}
+
+fn dates(week: &GridWeek) -> Vec<NaiveDate> {
+    week.days.iter().map(|day| day.date).collect()
+}
+
+fn week_numbers(grid: &MonthGrid) -> Vec<u32> {
+    grid.weeks().iter().map(|week| week.week.number()).collect()
+}
+
+#[test]
+fn grid_starts_on_first_weekday() {
+    let cases = [
+        (Weekday::Mon, date(2021, 3, 29)),
+        (Weekday::Sun, date(2021, 3, 28)),
+        (Weekday::Sat, date(2021, 3, 27)),
+        (Weekday::Thu, date(2021, 4, 1)),
+        (Weekday::Fri, date(2021, 3, 26)),
+    ];
+
+    for &(first_weekday, start) in &cases {
+        let grid = MonthGrid::new(2021, 4).with_first_weekday(first_weekday);
+        let weeks = grid.weeks();
+
+        assert_eq!(weeks.len(), 6);
+        assert_eq!(weeks[0].days[0].date, start, "{:?}", first_weekday);
+        assert_eq!(
+            weeks[5].days[6].date,
+            start + Duration::days(41),
+            "{:?}",
+            first_weekday
+        );
+    }
+}
+
+#[test]
+fn grid_uses_first_weekday_of_locale() {
+    let grid = MonthGrid::new(2021, 4).with_locale("en-US");
+    assert_eq!(grid.first_weekday(), Weekday::Sun);
+
+    let grid = MonthGrid::new(2021, 4).with_locale("de-DE");
+    assert_eq!(grid.first_weekday(), Weekday::Mon);
+
+    let grid = MonthGrid::new(2021, 4)
+        .with_locale("de-DE")
+        .with_first_weekday(Weekday::Sat);
+    assert_eq!(grid.first_weekday(), Weekday::Sat);
+}
+
+#[test]
+fn weekdays() {
+    let grid = MonthGrid::new(2021, 4).with_first_weekday(Weekday::Sat);
+
+    assert_eq!(
+        grid.weekdays(),
+        vec![
+            Weekday::Sat,
+            Weekday::Sun,
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+        ]
+    );
+}
+
+#[test]
+fn month_starting_on_first_weekday() {
+    let grid = MonthGrid::new(2021, 2).with_first_weekday(Weekday::Mon);
+
+    assert_eq!(first_days(&grid)[0], date(2021, 2, 1));
+}
+
+#[test]
+fn start_of_week_across_year_boundary() {
+    assert_eq!(
+        start_of_week(date(2021, 1, 1), Weekday::Mon),
+        date(2020, 12, 28)
+    );
+    assert_eq!(
+        start_of_week(date(2021, 1, 1), Weekday::Sun),
+        date(2020, 12, 27)
+    );
+    assert_eq!(
+        start_of_week(date(2021, 1, 1), Weekday::Sat),
+        date(2020, 12, 26)
+    );
+    assert_eq!(
+        start_of_week(date(2021, 1, 1), Weekday::Fri),
+        date(2021, 1, 1)
+    );
+}
+
+#[test]
+fn week_numbers_across_year_boundary() {
+    let grid = MonthGrid::new(2021, 1).with_first_weekday(Weekday::Mon);
+    assert_eq!(week_numbers(&grid), vec![53, 1, 2, 3, 4, 5]);
+
+    let grid = MonthGrid::new(2019, 12).with_first_weekday(Weekday::Mon);
+    assert_eq!(week_numbers(&grid), vec![48, 49, 50, 51, 52, 1]);
+
+    let grid = MonthGrid::new(2021, 1).with_first_weekday(Weekday::Sun);
+    assert_eq!(week_numbers(&grid), vec![53, 1, 2, 3, 4, 5]);
+
+    let grid = MonthGrid::new(2021, 1).with_first_weekday(Weekday::Sat);
+    assert_eq!(week_numbers(&grid), vec![53, 1, 2, 3, 4, 5]);
+
+    let grid = MonthGrid::new(2016, 1).with_first_weekday(Weekday::Mon);
+    assert_eq!(week_numbers(&grid), vec![53, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn week_numbers_stay_iso_whatever_the_locale() {
+    // Without a numbering scheme, rows are numbered like the ISO weeks they overlap most
+    let grid = MonthGrid::new(2021, 1).with_locale("en-US");
+    assert_eq!(grid.first_weekday(), Weekday::Sun);
+    assert_eq!(week_numbers(&grid), vec![53, 1, 2, 3, 4, 5]);
+
+    let grid = MonthGrid::new(2019, 12).with_locale("en-US");
+    assert_eq!(week_numbers(&grid), vec![49, 50, 51, 52, 1, 2]);
+
+    let grid = MonthGrid::new(2021, 1).with_locale("de-DE");
+    assert_eq!(week_numbers(&grid), vec![53, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn week_numbering_overrides_locale() {
+    let cases = [
+        (WeekNumbering::Iso, vec![48, 49, 50, 51, 52, 1]),
+        (WeekNumbering::Us, vec![49, 50, 51, 52, 1, 2]),
+        (WeekNumbering::MiddleEast, vec![49, 50, 51, 52, 53, 1]),
+    ];
+
+    for (numbering, numbers) in cases.iter().cloned() {
+        let grid = MonthGrid::new(2021, 12)
+            .with_locale("de-DE")
+            .with_first_weekday(Weekday::Wed)
+            .with_week_numbering(numbering);
+
+        assert_eq!(grid.first_weekday(), numbering.rule().first_weekday());
+        assert_eq!(grid.week_rule(), numbering.rule());
+        assert_eq!(week_numbers(&grid), numbers, "{:?}", numbering);
+    }
+}
+
+#[test]
+fn weeks_follow_the_week_rule() {
+    let grid = MonthGrid::new(2021, 4)
+        .with_locale("en-US")
+        .with_first_weekday(Weekday::Sat);
+    let rule = grid.week_rule();
+
+    assert_eq!(rule, WeekRule::iso_aligned(Weekday::Sat));
+    assert_eq!(rule, WeekRule::new(Weekday::Sat, 2));
+    for week in grid.weeks() {
+        assert_eq!(week.week, rule.week(week.days[0].date));
+        assert_eq!(week.week.days().collect::<Vec<_>>(), dates(&week));
+    }
+}
+
+#[test]
+fn day_states() {
+    let grid = MonthGrid::new(2021, 4)
+        .with_locale("en-US")
+        .with_selection(Selection::between(date(2021, 4, 12), date(2021, 4, 14)))
+        .with_today(date(2021, 4, 13))
+        .with_min_date(date(2021, 4, 2))
+        .with_disabled(|date| date.day() == 20);
+
+    let state = grid.day_state(date(2021, 4, 13));
+    assert!(state.in_month && state.today && !state.disabled && !state.weekend);
+    assert_eq!(state.selection, Some(Intersection::Inside));
+
+    assert!(grid.day_state(date(2021, 4, 1)).disabled);
+    assert!(grid.day_state(date(2021, 4, 20)).disabled);
+    assert!(grid.day_state(date(2021, 4, 17)).weekend);
+    assert!(!grid.day_state(date(2021, 5, 1)).in_month);
+
+    let grid = grid.with_today(date(2021, 5, 1));
+    assert!(!grid.day_state(date(2021, 5, 1)).today);
+}
+
+#[test]
+fn weeks_carry_selection_and_today() {
+    let grid = MonthGrid::new(2021, 4)
+        .with_first_weekday(Weekday::Mon)
+        .with_selection(Selection::between(date(2021, 4, 14), date(2021, 4, 30)))
+        .with_today(date(2021, 4, 20));
+    let weeks = grid.weeks();
+
+    assert_eq!(weeks[1].selection, None);
+    assert_eq!(weeks[2].selection, Some(Intersection::Start));
+    assert_eq!(weeks[3].selection, Some(Intersection::Inside));
+    assert_eq!(weeks[4].selection, Some(Intersection::End));
+    assert!(weeks[3].contains_today);
+    assert!(!weeks[4].contains_today);
+
+    // As for the days, today counts only if it's in the month
+    let weeks = grid.with_today(date(2021, 5, 2)).weeks();
+    assert!(weeks.iter().all(|week| !week.contains_today));
+    assert!(weeks[4].days.iter().all(|day| !day.state.today));
+}
+
+#[test]
+fn multiple_dates_are_selected_on_their_own() {
+    let dates = vec![date(2021, 4, 6), date(2021, 4, 8), date(2021, 4, 22)];
+    let grid = MonthGrid::new(2021, 4)
+        .with_first_weekday(Weekday::Mon)
+        .with_selection(Selection::from_dates(dates.clone()));
+
+    for week in grid.weeks() {
+        for day in &week.days {
+            let expected = dates.contains(&day.date).then_some(Intersection::All);
+            assert_eq!(day.state.selection, expected, "{}", day.date);
+        }
+    }
+
+    let rows: Vec<_> = grid.weeks().iter().map(|week| week.selection).collect();
+    assert_eq!(
+        rows,
+        vec![
+            None,
+            Some(Intersection::All),
+            None,
+            Some(Intersection::All),
+            None,
+            None
+        ]
+    );
+
+    let html = MonthView::<()>::new(2021, 4)
+        .with_first_weekday(Weekday::Mon)
+        .with_selected_dates(dates)
+        .into_html();
+    assert_eq!(html.matches("aria-selected=\"true\"").count(), 3);
+    assert_eq!(
+        html.matches("<tr role=\"row\" class=\"selected\"").count(),
+        2
+    );
+}
+
+#[test]
+fn invalid_selection() {
+    let grid = MonthGrid::new(2021, 4)
+        .with_selection(Selection::between(date(2021, 4, 12), date(2021, 4, 14)))
+        .with_max_date(date(2021, 4, 13));
+
+    assert!(grid.has_invalid_selection());
+    assert!(!grid
+        .with_max_date(date(2021, 4, 14))
+        .has_invalid_selection());
+}
+
+#[test]
+fn first_and_last_of_month() {
+    let grid = MonthGrid::new(2020, 2);
+
+    assert_eq!(grid.first_of_month(), date(2020, 2, 1));
+    assert_eq!(grid.last_of_month(), date(2020, 2, 29));
+}
+
+#[test]
+fn navigation_is_limited_by_min_and_max_dates() {
+    let grid = MonthGrid::new(2021, 4);
+    assert_eq!(grid.previous_month(), Some((2021, 3)));
+    assert_eq!(grid.next_month(), Some((2021, 5)));
+
+    let grid = MonthGrid::new(2021, 4)
+        .with_min_date(date(2021, 4, 1))
+        .with_max_date(date(2021, 4, 30));
+    assert_eq!(grid.previous_month(), None);
+    assert_eq!(grid.next_month(), None);
+
+    let grid = MonthGrid::new(2021, 12)
+        .with_min_date(date(2021, 11, 30))
+        .with_max_date(date(2022, 1, 1));
+    assert_eq!(grid.previous_month(), Some((2021, 11)));
+    assert_eq!(grid.next_month(), Some((2022, 1)));
+}
+
+#[test]
+fn navigation_labels() {
+    let html = MonthView::<()>::new(2021, 4)
+        .show_header()
+        .on_navigate(|_, _| ())
+        .into_html();
+    assert!(html.contains("aria-label=\"Previous month\">"));
+    assert!(html.contains("aria-label=\"Next month\">"));
+
+    let html = MonthView::<()>::new(2021, 4)
+        .with_locale("de-DE")
+        .with_navigation_labels("Vorheriger Monat", "Nächster Monat")
+        .show_header()
+        .into_html();
+    assert!(html.contains("aria-label=\"Vorheriger Monat\""));
+    assert!(html.contains("aria-label=\"Nächster Monat\""));
+    assert!(!html.contains("Previous month"));
+}
+
+#[test]
+fn navigation_buttons_are_disabled_without_a_handler() {
+    let html = MonthView::<()>::new(2021, 4).show_header().into_html();
+    assert_eq!(html.matches(" disabled").count(), 2);
+
+    let html = MonthView::<()>::new(2021, 4)
+        .show_header()
+        .on_navigate(|_, _| ())
+        .with_min_date(date(2021, 4, 1))
+        .into_html();
+    assert_eq!(html.matches(" disabled").count(), 1);
+}
+
+#[test]
+fn tabbable_date() {
+    let grid = MonthGrid::new(2021, 4).with_min_date(date(2021, 4, 3));
+    assert_eq!(grid.tabbable_date(None), date(2021, 4, 3));
+    assert_eq!(grid.tabbable_date(Some(date(2021, 4, 2))), date(2021, 4, 3));
+    assert_eq!(grid.tabbable_date(Some(date(2021, 5, 2))), date(2021, 4, 3));
+
+    let grid = grid.with_today(date(2021, 4, 13));
+    assert_eq!(grid.tabbable_date(None), date(2021, 4, 13));
+
+    let grid = grid.with_selection(Selection::between(date(2021, 4, 20), date(2021, 4, 22)));
+    assert_eq!(grid.tabbable_date(None), date(2021, 4, 20));
+    assert_eq!(grid.tabbable_date(Some(date(2021, 4, 8))), date(2021, 4, 8));
+}
+
+// Properties
+
+fn any_weekday() -> impl Strategy<Value = Weekday> {
+    prop::sample::select(vec![
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ])
+}
+
+fn any_calendar() -> impl Strategy<Value = Calendar> {
+    prop::sample::select(vec![
+        Calendar::Gregorian,
+        Calendar::Buddhist,
+        Calendar::Japanese,
+        Calendar::Persian,
+        Calendar::Hebrew,
+        Calendar::IslamicCivil,
+        Calendar::Islamic,
+        Calendar::IslamicUmalqura,
+    ])
+}
+
+fn any_date() -> impl Strategy<Value = NaiveDate> {
+    // 1900-01-01 to 2100-12-31
+    (693_596..766_645i32).prop_map(NaiveDate::from_num_days_from_ce)
+}
+
+proptest! {
+    #[test]
+    fn grid_contains_whole_month(
+        year in 1900..2100i32,
+        month in 1..=12u32,
+        first_weekday in any_weekday(),
+    ) {
+        let grid = MonthGrid::new(year, month).with_first_weekday(first_weekday);
+        let days: Vec<_> = grid.weeks().into_iter().flat_map(|week| week.days).collect();
+
+        prop_assert_eq!(days.len(), 42);
+        prop_assert_eq!(days[0].date.weekday(), first_weekday);
+        for pair in days.windows(2) {
+            prop_assert_eq!(pair[1].date, pair[0].date.succ());
+        }
+        for day in &days {
+            let in_month = day.date.year() == year && day.date.month() == month;
+            prop_assert_eq!(day.state.in_month, in_month);
+        }
+
+        let in_month = days.iter().filter(|day| day.state.in_month).count();
+        let last_of_month = grid.last_of_month();
+        prop_assert_eq!(in_month as u32, last_of_month.day());
+        prop_assert!(days[0].date <= grid.first_of_month());
+        prop_assert!(days[41].date >= last_of_month);
+    }
+
+    #[test]
+    fn every_week_starts_on_first_weekday(
+        year in 1900..2100i32,
+        month in 1..=12u32,
+        first_weekday in any_weekday(),
+    ) {
+        let grid = MonthGrid::new(year, month).with_first_weekday(first_weekday);
+
+        for week in grid.weeks() {
+            prop_assert_eq!(week.days.len(), 7);
+            prop_assert_eq!(week.days[0].date.weekday(), first_weekday);
+        }
+    }
+
+    #[test]
+    fn grid_contains_whole_month_in_any_calendar(
+        date in any_date(),
+        calendar in any_calendar(),
+        first_weekday in any_weekday(),
+    ) {
+        let calendar_date = calendar.from_date(date);
+        let grid = MonthGrid::new(calendar_date.year, calendar_date.month)
+            .with_calendar(calendar)
+            .with_first_weekday(first_weekday);
+        let days: Vec<_> = grid.weeks().into_iter().flat_map(|week| week.days).collect();
+        let in_month: Vec<_> = days.iter().filter(|day| day.state.in_month).collect();
+
+        prop_assert!(in_month.iter().any(|day| day.date == date));
+        prop_assert_eq!(
+            in_month.len() as u32,
+            calendar.days_in_month(calendar_date.year, calendar_date.month)
+        );
+        for (index, day) in in_month.iter().enumerate() {
+            prop_assert_eq!(day.day, index as u32 + 1);
+        }
+        for pair in in_month.windows(2) {
+            prop_assert_eq!(pair[1].date, pair[0].date.succ());
+        }
+    }
+
+    #[test]
+    fn start_of_week_is_within_the_week(date in any_date(), first_weekday in any_weekday()) {
+        let start = start_of_week(date, first_weekday);
+
+        prop_assert_eq!(start.weekday(), first_weekday);
+        prop_assert!(start <= date);
+        prop_assert!(date - start < Duration::days(7));
+    }
+
+    #[test]
+    fn week_numbers_are_consecutive(
+        year in 1900..2100i32,
+        month in 1..=12u32,
+        first_weekday in any_weekday(),
+    ) {
+        let grid = MonthGrid::new(year, month).with_first_weekday(first_weekday);
+        let numbers = week_numbers(&grid);
+
+        for pair in numbers.windows(2) {
+            prop_assert!(
+                pair[1] == pair[0] + 1 || (pair[1] == 1 && (pair[0] == 52 || pair[0] == 53)),
+                "{:?}",
+                numbers
+            );
+        }
+    }
+}
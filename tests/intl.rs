@@ -0,0 +1,56 @@
+//! Runs with the wasm-bindgen test runner, as `Intl` isn't available natively:
+//!
+//! ```text
+//! wasm-pack test --node
+//! ```
+//!
+//! Dates must be formatted as the same day in every time zone, which is worth checking far from
+//! UTC, e.g. with `TZ=Pacific/Kiritimati`.
+
+#![cfg(target_arch = "wasm32")]
+
+use chrono::NaiveDate;
+use seed_calendar::calendar::{Calendar, CalendarDate};
+use seed_calendar::view::MonthView;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn day_labels() {
+    let html = MonthView::<()>::new(2021, 4)
+        .with_locale("en-US")
+        .into_html();
+
+    assert!(html.contains("aria-label=\"Thursday, April 1, 2021\""));
+    assert!(html.contains("aria-label=\"Friday, April 30, 2021\""));
+}
+
+// Where Umm al-Qura differs from the tabular Islamic calendar, which starts the year a day later
+#[wasm_bindgen_test]
+fn umm_al_qura_month_start() {
+    let new_year = NaiveDate::from_ymd(2021, 8, 9);
+
+    assert_eq!(
+        Calendar::IslamicUmalqura.from_date(new_year),
+        CalendarDate {
+            year: 1443,
+            month: 1,
+            day: 1
+        }
+    );
+    assert_eq!(
+        Calendar::IslamicUmalqura.first_of_month(1443, 1),
+        Some(new_year)
+    );
+}
+
+#[wasm_bindgen_test]
+fn secondary_calendar_annotations() {
+    let html = MonthView::<()>::new(2021, 8)
+        .with_locale("en-US")
+        .with_secondary_calendar(Calendar::IslamicUmalqura.identifier())
+        .into_html();
+
+    assert!(html.contains(">9<small class=\"secondary\">Muh. 1</small>"));
+    assert!(html.contains(">10<small class=\"secondary\">2</small>"));
+    assert!(html.contains("aria-label=\"Monday, August 9, 2021 (Monday, Muharram 1, 1443 AH)\""));
+}
@@ -0,0 +1,52 @@
+use seed_calendar::core::MonthGrid;
+use seed_calendar::{Error, Locale};
+
+#[test]
+fn canonicalizes_case() {
+    for (tag, canonical) in &[
+        ("EN-us", "en-US"),
+        ("zh-hant-tw", "zh-Hant-TW"),
+        ("TH-th-U-NU-THAI", "th-TH-u-nu-thai"),
+        ("es-419", "es-419"),
+        ("de-CH-1996", "de-CH-1996"),
+    ] {
+        assert_eq!(Locale::new(tag).unwrap().as_str(), *canonical);
+    }
+
+    assert_eq!(
+        "en_US".parse::<Locale>(),
+        Err(Error::UnsupportedLocale(String::from("en_US")))
+    );
+}
+
+#[test]
+fn malformed_locale_falls_back_to_default() {
+    assert_eq!(Locale::from("english!"), Locale::default());
+    assert_eq!(
+        MonthGrid::new(2021, 4).with_locale("en--US").locale(),
+        &Locale::default()
+    );
+    assert_eq!(
+        MonthGrid::new(2021, 4)
+            .with_locale("de-de")
+            .locale()
+            .as_str(),
+        "de-DE"
+    );
+}
+
+#[test]
+fn negotiates_first_supported_preference() {
+    let locale = Locale::negotiate(&["xx-YY", "en_GB", "en-gb", "en-US"]);
+    assert_eq!(locale.map(String::from).as_deref(), Some("en-GB"));
+
+    assert_eq!(Locale::negotiate(&["xx", "not a tag"]), None);
+    assert_eq!(Locale::negotiate(Vec::<String>::new()), None);
+}
+
+#[test]
+fn resolves_to_bundled_language() {
+    assert_eq!(Locale::from("en-GB").resolved().as_str(), "en");
+    assert_eq!(Locale::from("xx-YY").resolved().as_str(), "en");
+    assert_eq!(Locale::from("th-TH-u-nu-thai").language(), "th");
+}
@@ -0,0 +1,218 @@
+// proptest is only a dependency on native targets
+#![cfg(not(target_arch = "wasm32"))]
+
+use chrono::{Duration, NaiveDate};
+use proptest::prelude::*;
+use seed_calendar::core::{Intersection, Selection};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd(year, month, day)
+}
+
+#[test]
+fn between_orders_the_dates() {
+    let (a, b) = (date(2021, 4, 1), date(2021, 4, 10));
+
+    assert_eq!(Selection::between(a, b), Selection::Range(a, b));
+    assert_eq!(Selection::between(b, a), Selection::Range(a, b));
+    assert_eq!(Selection::between(a, a), Selection::Single(a));
+}
+
+#[test]
+fn from_bounds() {
+    let (a, b) = (date(2021, 4, 1), date(2021, 4, 10));
+
+    assert_eq!(Selection::from_bounds(None, None), Selection::None);
+    assert_eq!(Selection::from_bounds(Some(a), None), Selection::Single(a));
+    assert_eq!(Selection::from_bounds(None, Some(b)), Selection::Single(b));
+    assert_eq!(
+        Selection::from_bounds(Some(b), Some(a)),
+        Selection::Range(a, b)
+    );
+}
+
+#[test]
+fn from_dates() {
+    let (a, b) = (date(2021, 4, 1), date(2021, 4, 10));
+
+    assert_eq!(Selection::from_dates(None), Selection::None);
+    assert_eq!(
+        Selection::from_dates(vec![b, a, b]),
+        Selection::Multiple(vec![a, b].into_iter().collect())
+    );
+    assert_eq!(Selection::from_dates(vec![b, a]).start(), Some(a));
+}
+
+#[test]
+fn intersects_single() {
+    let selection = Selection::Single(date(2021, 4, 12));
+
+    assert_eq!(
+        selection.intersects(date(2021, 4, 12)),
+        Some(Intersection::All)
+    );
+    assert_eq!(selection.intersects(date(2021, 4, 11)), None);
+    assert_eq!(Selection::None.intersects(date(2021, 4, 12)), None);
+}
+
+#[test]
+fn intersects_range() {
+    let selection = Selection::Range(date(2021, 4, 12), date(2021, 4, 14));
+
+    assert_eq!(selection.intersects(date(2021, 4, 11)), None);
+    assert_eq!(
+        selection.intersects(date(2021, 4, 12)),
+        Some(Intersection::Start)
+    );
+    assert_eq!(
+        selection.intersects(date(2021, 4, 13)),
+        Some(Intersection::Inside)
+    );
+    assert_eq!(
+        selection.intersects(date(2021, 4, 14)),
+        Some(Intersection::End)
+    );
+    assert_eq!(selection.intersects(date(2021, 4, 15)), None);
+}
+
+#[test]
+fn intersects_week() {
+    let (monday, sunday) = (date(2021, 4, 12), date(2021, 4, 18));
+
+    let cases = [
+        (
+            Selection::Single(date(2021, 4, 14)),
+            Some(Intersection::All),
+        ),
+        (Selection::Range(monday, sunday), Some(Intersection::All)),
+        (
+            Selection::Range(date(2021, 4, 14), date(2021, 4, 30)),
+            Some(Intersection::Start),
+        ),
+        (
+            Selection::Range(date(2021, 4, 1), date(2021, 4, 14)),
+            Some(Intersection::End),
+        ),
+        (
+            Selection::Range(date(2021, 4, 1), date(2021, 4, 30)),
+            Some(Intersection::Inside),
+        ),
+        (Selection::Range(date(2021, 4, 1), date(2021, 4, 11)), None),
+        (Selection::Single(date(2021, 4, 19)), None),
+    ];
+
+    for (selection, expected) in &cases {
+        assert_eq!(
+            selection.intersects_range(monday, sunday),
+            *expected,
+            "{:?}",
+            selection
+        );
+    }
+}
+
+#[test]
+fn intersects_multiple() {
+    let selection = Selection::from_dates(vec![date(2021, 4, 12), date(2021, 4, 14)]);
+
+    assert_eq!(
+        selection.intersects(date(2021, 4, 12)),
+        Some(Intersection::All)
+    );
+    assert_eq!(selection.intersects(date(2021, 4, 13)), None);
+    assert_eq!(
+        selection.intersects(date(2021, 4, 14)),
+        Some(Intersection::All)
+    );
+
+    // Weeks with any of the dates are selected, like with a single date
+    assert_eq!(
+        selection.intersects_range(date(2021, 4, 5), date(2021, 4, 12)),
+        Some(Intersection::All)
+    );
+    assert_eq!(
+        selection.intersects_range(date(2021, 4, 15), date(2021, 4, 21)),
+        None
+    );
+}
+
+#[test]
+fn intersects_across_year_boundary() {
+    let selection = Selection::Range(date(2020, 12, 30), date(2021, 1, 2));
+
+    assert_eq!(
+        selection.intersects(date(2020, 12, 31)),
+        Some(Intersection::Inside)
+    );
+    assert_eq!(
+        selection.intersects(date(2021, 1, 1)),
+        Some(Intersection::Inside)
+    );
+    assert_eq!(
+        selection.intersects_range(date(2020, 12, 28), date(2021, 1, 3)),
+        Some(Intersection::Start)
+    );
+}
+
+#[test]
+fn includes_any() {
+    let selection = Selection::Range(date(2021, 4, 12), date(2021, 4, 14));
+
+    assert!(selection.includes_any(|selected| selected == date(2021, 4, 13)));
+    assert!(!selection.includes_any(|selected| selected == date(2021, 4, 15)));
+    assert!(!Selection::None.includes_any(|_| true));
+}
+
+// Properties
+
+fn any_date() -> impl Strategy<Value = NaiveDate> {
+    // 1900-01-01 to 2100-12-31
+    (693_596..766_645i32).prop_map(NaiveDate::from_num_days_from_ce)
+}
+
+fn any_selection() -> impl Strategy<Value = Selection> {
+    prop_oneof![
+        Just(Selection::None),
+        any_date().prop_map(Selection::Single),
+        (any_date(), 0..400i64)
+            .prop_map(|(start, days)| Selection::between(start, start + Duration::days(days))),
+        (any_date(), prop::collection::vec(0..60i64, 0..8)).prop_map(|(start, days)| {
+            Selection::from_dates(days.into_iter().map(|days| start + Duration::days(days)))
+        }),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn range_intersection_is_consistent_with_days(
+        selection in any_selection(),
+        start in any_date(),
+        days in 0..42i64,
+    ) {
+        let end = start + Duration::days(days);
+        let any_day = start
+            .iter_days()
+            .take_while(|&date| date <= end)
+            .any(|date| selection.intersects(date).is_some());
+
+        prop_assert_eq!(selection.intersects_range(start, end).is_some(), any_day);
+    }
+
+    #[test]
+    fn single_day_range_intersects_like_the_day(selection in any_selection(), date in any_date()) {
+        prop_assert_eq!(selection.intersects_range(date, date), selection.intersects(date));
+    }
+
+    #[test]
+    fn between_is_symmetric(a in any_date(), b in any_date()) {
+        prop_assert_eq!(Selection::between(a, b), Selection::between(b, a));
+    }
+
+    #[test]
+    fn includes_any_matches_intersects(selection in any_selection(), date in any_date()) {
+        prop_assert_eq!(
+            selection.includes_any(|selected| selected == date),
+            selection.intersects(date).is_some()
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use chrono::NaiveDate;
+use seed_calendar::view::{MonthsView, YearsView};
+
+fn tab_stops(html: &str) -> usize {
+    html.matches("tabindex=\"0\"").count()
+}
+
+#[test]
+fn months_have_a_single_tab_stop() {
+    let html = MonthsView::<()>::new().with_year(2021).into_html();
+    assert_eq!(tab_stops(&html), 1);
+    assert_eq!(html.matches("tabindex=\"-1\"").count(), 11);
+
+    let html = MonthsView::<()>::new()
+        .with_year(2021)
+        .with_today(NaiveDate::from_ymd(2021, 4, 13))
+        .into_html();
+    assert!(html.contains("aria-label=\"April\" tabindex=\"0\""));
+
+    let html = MonthsView::<()>::new()
+        .with_year(2021)
+        .with_today(NaiveDate::from_ymd(2021, 4, 13))
+        .with_selected(6)
+        .into_html();
+    assert!(html.contains("aria-label=\"June\" tabindex=\"0\""));
+    assert_eq!(tab_stops(&html), 1);
+}
+
+#[test]
+fn years_have_a_single_tab_stop() {
+    let html = YearsView::<()>::decade_from(2010).into_html();
+    assert_eq!(tab_stops(&html), 1);
+    assert!(html.contains("tabindex=\"0\">2010<"));
+
+    let html = YearsView::<()>::decade_from(2010)
+        .with_selected(2014)
+        .with_focused(2017)
+        .into_html();
+    assert_eq!(tab_stops(&html), 1);
+    assert!(html.contains("tabindex=\"0\">2017<"));
+}
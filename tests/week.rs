@@ -0,0 +1,215 @@
+// proptest is only a dependency on native targets
+#![cfg(not(target_arch = "wasm32"))]
+
+use chrono::naive::MAX_DATE;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use proptest::prelude::*;
+use seed_calendar::core::{Week, WeekNumbering, WeekRule};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd(year, month, day)
+}
+
+#[test]
+fn week_of_date() {
+    let us = WeekRule::new(Weekday::Sun, 1);
+    let week = us.week(date(2021, 4, 14));
+
+    assert_eq!((week.year(), week.number()), (2021, 16));
+    assert_eq!(week.first_day(), date(2021, 4, 11));
+    assert_eq!(week.last_day(), date(2021, 4, 17));
+    assert!(week.contains(date(2021, 4, 17)));
+    assert!(!week.contains(date(2021, 4, 18)));
+}
+
+#[test]
+fn weeks_belong_to_the_year_with_the_minimal_days() {
+    // 2021-01-01 is a Friday, so a Sunday week has two days of 2021
+    let week = WeekRule::new(Weekday::Sun, 1).week(date(2020, 12, 28));
+    assert_eq!((week.year(), week.number()), (2021, 1));
+
+    let week = WeekRule::new(Weekday::Sun, 3).week(date(2020, 12, 28));
+    assert_eq!((week.year(), week.number()), (2020, 53));
+
+    let week = WeekRule::ISO.week(date(2021, 1, 3));
+    assert_eq!((week.year(), week.number()), (2020, 53));
+}
+
+#[test]
+fn week_numbering_at_year_boundaries() {
+    let cases = [
+        (WeekNumbering::Iso, date(2019, 12, 30), (2020, 1)),
+        (WeekNumbering::Iso, date(2021, 1, 3), (2020, 53)),
+        (WeekNumbering::Iso, date(2021, 1, 4), (2021, 1)),
+        (WeekNumbering::Us, date(2020, 12, 26), (2020, 52)),
+        (WeekNumbering::Us, date(2020, 12, 27), (2021, 1)),
+        (WeekNumbering::Us, date(2021, 1, 2), (2021, 1)),
+        (WeekNumbering::Us, date(2021, 1, 3), (2021, 2)),
+        (WeekNumbering::MiddleEast, date(2021, 1, 1), (2021, 1)),
+        (WeekNumbering::MiddleEast, date(2021, 12, 31), (2021, 53)),
+        (WeekNumbering::MiddleEast, date(2022, 1, 1), (2022, 1)),
+        (
+            WeekNumbering::Custom(WeekRule::new(Weekday::Sun, 7)),
+            date(2021, 1, 2),
+            (2020, 52),
+        ),
+        (
+            WeekNumbering::Custom(WeekRule::new(Weekday::Sun, 7)),
+            date(2021, 1, 3),
+            (2021, 1),
+        ),
+    ];
+
+    for &(numbering, date, expected) in &cases {
+        let week = numbering.rule().week(date);
+
+        assert_eq!(
+            (week.year(), week.number()),
+            expected,
+            "{:?} {}",
+            numbering,
+            date
+        );
+    }
+}
+
+#[test]
+fn week_numbering_decides_first_weekday() {
+    assert_eq!(WeekNumbering::Iso.rule(), WeekRule::ISO);
+    assert_eq!(WeekNumbering::Us.rule().first_weekday(), Weekday::Sun);
+    assert_eq!(
+        WeekNumbering::MiddleEast.rule().first_weekday(),
+        Weekday::Sat
+    );
+    assert_eq!(
+        WeekNumbering::from(WeekRule::new(Weekday::Wed, 2)).rule(),
+        WeekRule::new(Weekday::Wed, 2)
+    );
+}
+
+#[test]
+fn week_from_iso_week() {
+    let week = Week::from(date(2021, 1, 1).iso_week());
+
+    assert_eq!(week, WeekRule::ISO.week(date(2020, 12, 28)));
+    assert_eq!(week.rule(), WeekRule::default());
+}
+
+#[test]
+fn iso_aligned_weeks() {
+    assert_eq!(WeekRule::iso_aligned(Weekday::Mon), WeekRule::ISO);
+
+    let week = WeekRule::iso_aligned(Weekday::Sun).week(date(2021, 1, 3));
+    assert_eq!((week.year(), week.number()), (2021, 1));
+    assert_eq!(week.last_day(), date(2021, 1, 9));
+
+    let week = WeekRule::iso_aligned(Weekday::Wed).week(date(2021, 1, 3));
+    assert_eq!((week.year(), week.number()), (2020, 53));
+}
+
+#[test]
+fn minimal_days_are_clamped() {
+    assert_eq!(WeekRule::new(Weekday::Mon, 0).minimal_days(), 1);
+    assert_eq!(WeekRule::new(Weekday::Mon, 10).minimal_days(), 7);
+}
+
+#[test]
+fn nonexistent_weeks() {
+    assert_eq!(Week::new(2021, 0, WeekRule::ISO), None);
+    assert_eq!(Week::new(2021, 53, WeekRule::ISO), None);
+    assert!(Week::new(2020, 53, WeekRule::ISO).is_some());
+
+    // Years outside the range of dates
+    assert_eq!(Week::new(262144, 1, WeekRule::ISO), None);
+    assert_eq!(Week::new(-262145, 1, WeekRule::ISO), None);
+}
+
+#[test]
+fn weeks_at_the_ends_of_the_range_of_dates() {
+    let week = WeekRule::ISO.week(MAX_DATE);
+    assert_eq!(week.year(), 262143);
+    assert!(week.contains(MAX_DATE));
+
+    let week = WeekRule::new(Weekday::Sun, 1).week(date(-262144, 1, 10));
+    assert_eq!(week.year(), -262144);
+    assert!(week.contains(date(-262144, 1, 10)));
+}
+
+fn any_weekday() -> impl Strategy<Value = Weekday> {
+    prop::sample::select(vec![
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ])
+}
+
+fn any_rule() -> impl Strategy<Value = WeekRule> {
+    (any_weekday(), 1..=7u32)
+        .prop_map(|(first_weekday, minimal_days)| WeekRule::new(first_weekday, minimal_days))
+}
+
+fn any_date() -> impl Strategy<Value = NaiveDate> {
+    // 1900-01-01 to 2100-12-31
+    (693_596..766_645i32).prop_map(NaiveDate::from_num_days_from_ce)
+}
+
+proptest! {
+    #[test]
+    fn week_contains_date(date in any_date(), rule in any_rule()) {
+        let week = rule.week(date);
+
+        prop_assert!(week.contains(date));
+        prop_assert_eq!(week.first_day().weekday(), rule.first_weekday());
+        prop_assert_eq!(week.days().count(), 7);
+        prop_assert!(week.days().all(|day| rule.week(day) == week));
+    }
+
+    #[test]
+    fn week_has_the_minimal_days_of_its_year(date in any_date(), rule in any_rule()) {
+        let week = rule.week(date);
+
+        if week.number() == 1 {
+            let days = week.days().filter(|day| day.year() == week.year()).count() as u32;
+            prop_assert!(days >= rule.minimal_days());
+        }
+        prop_assert!((week.year() - date.year()).abs() <= 1);
+    }
+
+    #[test]
+    fn weeks_are_consecutive(date in any_date(), rule in any_rule()) {
+        let week = rule.week(date);
+        let next = week.succ();
+
+        prop_assert_eq!(next.first_day(), week.first_day() + Duration::weeks(1));
+        prop_assert_eq!(next.pred(), week);
+        prop_assert!(next > week);
+        prop_assert!(
+            (next.year(), next.number()) == (week.year(), week.number() + 1)
+                || (next.year(), next.number()) == (week.year() + 1, 1)
+        );
+        prop_assert_eq!(Week::new(week.year(), week.number(), rule), Some(week));
+    }
+
+    #[test]
+    fn iso_aligned_weeks_are_numbered_by_most_of_their_days(
+        date in any_date(),
+        first_weekday in any_weekday(),
+    ) {
+        let week = WeekRule::iso_aligned(first_weekday).week(date);
+        let majority = week.days().nth(3).unwrap().iso_week();
+
+        prop_assert_eq!((week.year(), week.number()), (majority.year(), majority.week()));
+    }
+
+    #[test]
+    fn iso_rule_agrees_with_chrono(date in any_date()) {
+        let week = WeekRule::ISO.week(date);
+        let iso_week = date.iso_week();
+
+        prop_assert_eq!((week.year(), week.number()), (iso_week.year(), iso_week.week()));
+    }
+}